@@ -1,10 +1,12 @@
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use portmanteau::portmanteau;
+use portmanteau::{
+    portmanteau, JoinMethod, JoinPoints, Portmanteau, SharedTrios, Strategy,
+};
 
-pub fn csv_file(criterion: &mut Criterion) {
+fn input_pairs() -> Vec<(String, String)> {
     let mut reader = csv::Reader::from_path("benches/input_pairs.csv")
         .expect("unable to find input file for benchmark");
-    let input_pairs = reader
+    reader
         .records()
         .map(|record| {
             let record = record.expect("failed to parse input file");
@@ -12,7 +14,11 @@ pub fn csv_file(criterion: &mut Criterion) {
             let right_word = String::from(record.get(1).unwrap());
             (left_word, right_word)
         })
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
+
+pub fn csv_file(criterion: &mut Criterion) {
+    let input_pairs = input_pairs();
 
     criterion.bench_with_input(
         BenchmarkId::new("CSV file", input_pairs.len()),
@@ -27,5 +33,381 @@ pub fn csv_file(criterion: &mut Criterion) {
     );
 }
 
-criterion_group!(benches, csv_file);
+/// The trio matching code path before it used an index: every trio of the
+/// left word compared with every trio of the right word
+#[derive(Debug)]
+struct NestedScan;
+
+impl Strategy for NestedScan {
+    fn method(&self) -> JoinMethod {
+        JoinMethod::SharedTrio
+    }
+
+    fn join_points<'a>(
+        &'a self,
+        _: &'a Portmanteau,
+        a: &'a str,
+        b: &'a str,
+    ) -> JoinPoints<'a> {
+        let a_trios = trios_of(a);
+        let b_trios = trios_of(b);
+        let b_trios = &b_trios[..b_trios.len().saturating_sub(2)];
+        let join_points = a_trios
+            .iter()
+            .skip(1)
+            .rev()
+            .flat_map(|(a_index, a_trio)| {
+                b_trios
+                    .iter()
+                    .filter(move |(_, b_trio)| a_trio == b_trio)
+                    .map(move |(b_index, _)| (*a_index, *b_index))
+            })
+            .collect::<Vec<_>>();
+        Box::new(join_points.into_iter())
+    }
+}
+
+/// The trio matching code path when it always sorted the right word's trios
+/// to look them up
+#[derive(Debug)]
+struct SortedIndex;
+
+impl Strategy for SortedIndex {
+    fn method(&self) -> JoinMethod {
+        JoinMethod::SharedTrio
+    }
+
+    fn join_points<'a>(
+        &'a self,
+        _: &'a Portmanteau,
+        a: &'a str,
+        b: &'a str,
+    ) -> JoinPoints<'a> {
+        let a_trios = trios_of(a);
+        let mut b_trios = trios_of(b);
+        b_trios.truncate(b_trios.len().saturating_sub(2));
+        b_trios.sort_unstable_by_key(|(b_index, b_trio)| (*b_trio, *b_index));
+        let join_points = a_trios
+            .iter()
+            .skip(1)
+            .rev()
+            .flat_map(|(a_index, a_trio)| {
+                let start =
+                    b_trios.partition_point(|(_, b_trio)| b_trio < a_trio);
+                b_trios[start..]
+                    .iter()
+                    .take_while(move |(_, b_trio)| b_trio == a_trio)
+                    .map(move |(b_index, _)| (*a_index, *b_index))
+            })
+            .collect::<Vec<_>>();
+        Box::new(join_points.into_iter())
+    }
+}
+
+/// Each trio of a word, and the byte index it starts at
+fn trios_of(word: &str) -> Vec<(usize, &str)> {
+    let starts = word
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(Some(word.len()))
+        .collect::<Vec<_>>();
+    starts
+        .windows(4)
+        .map(|window| (window[0], &word[window[0]..window[3]]))
+        .collect()
+}
+
+/// Only the trio matching code path, which is the most expensive, compared
+/// with how it used to work
+pub fn shared_trios(criterion: &mut Criterion) {
+    let generators = [
+        (
+            "Nested scan",
+            Portmanteau::builder()
+                .clear_strategies()
+                .strategy(NestedScan)
+                .build(),
+        ),
+        (
+            "Sorted index",
+            Portmanteau::builder()
+                .clear_strategies()
+                .strategy(SortedIndex)
+                .build(),
+        ),
+        (
+            "Current",
+            Portmanteau::builder()
+                .clear_strategies()
+                .strategy(SharedTrios)
+                .build(),
+        ),
+    ];
+    let mut group = criterion.benchmark_group("Shared trios");
+    group.sample_size(10);
+
+    // Every word from the CSV file blended with every other word, then pairs
+    // of words joined together so there are lots of trios to compare
+    let input_pairs = input_pairs();
+    let words = input_pairs
+        .iter()
+        .flat_map(|(left_word, right_word)| [left_word, right_word])
+        .collect::<Vec<_>>();
+    let mut inputs = vec![(
+        String::from("CSV words cross product"),
+        words
+            .iter()
+            .flat_map(|left_word| {
+                words.iter().map(move |right_word| {
+                    (left_word.to_string(), right_word.to_string())
+                })
+            })
+            .collect::<Vec<_>>(),
+    )];
+    for length in [2, 4, 8] {
+        let long_pairs = words
+            .chunks_exact(2 * length)
+            .map(|chunk| {
+                let (left, right) = chunk.split_at(length);
+                (
+                    left.iter().map(|word| word.as_str()).collect::<String>(),
+                    right.iter().map(|word| word.as_str()).collect::<String>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        inputs.push((format!("{} words joined together", length), long_pairs));
+    }
+
+    for (input_name, pairs) in &inputs {
+        for (name, generator) in &generators {
+            // Every implementation should find the same portmanteaux
+            assert!(pairs.iter().all(|(left_word, right_word)| {
+                generator.generate(left_word, right_word)
+                    == generators[2].1.generate(left_word, right_word)
+            }));
+            group.bench_with_input(
+                BenchmarkId::new(*name, input_name),
+                pairs,
+                |bencher, pairs| {
+                    bencher.iter(|| {
+                        pairs.iter().for_each(|(left_word, right_word)| {
+                            generator.generate(left_word, right_word);
+                        });
+                    });
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+/// The crate as it was before portmanteaux could be configured, when
+/// [`portmanteau`] was a plain function of the two words, for comparing the
+/// default settings with
+mod baseline {
+    const MIN_WORD_SIZE: usize = 4;
+    const MATCHING_VOWEL_SEARCH_MARGIN: usize = 1;
+    const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
+
+    fn vowels_ltr(word: &str) -> [Option<usize>; 5] {
+        let substring = &word[..word.len() - MATCHING_VOWEL_SEARCH_MARGIN];
+        VOWELS.map(|vowel| substring.find(vowel))
+    }
+
+    fn vowels_rtl(word: &str) -> [Option<usize>; 5] {
+        let substring = &word[MATCHING_VOWEL_SEARCH_MARGIN..];
+        VOWELS.map(|vowel| {
+            substring
+                .rfind(vowel)
+                .map(|index| index + MATCHING_VOWEL_SEARCH_MARGIN)
+        })
+    }
+
+    fn trios_of(
+        string: &str,
+    ) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        (0..string.len() - 2).map(move |index| &string[index..index + 3])
+    }
+
+    fn portmanteau_by_trios(a: &str, b: &str) -> Option<String> {
+        trios_of(a)
+            .skip(1)
+            .enumerate()
+            .rev()
+            .flat_map(|a_trio_tup| {
+                let b_trios = trios_of(b).enumerate().rev().skip(2).rev();
+                b_trios.map(move |b_trio_tup| (a_trio_tup, b_trio_tup))
+            })
+            .filter(|((_, a_trio), (_, b_trio))| a_trio == b_trio)
+            .find_map(|((a_pos, _), (b_pos, _))| {
+                make_if_acceptable(a, a_pos + 1, b, b_pos)
+            })
+    }
+
+    fn validate(s: &str) -> bool {
+        s.len() >= MIN_WORD_SIZE && s.chars().all(|c| c.is_ascii_lowercase())
+    }
+
+    fn make_if_acceptable(
+        left_word: &str,
+        left_index: usize,
+        right_word: &str,
+        right_index: usize,
+    ) -> Option<String> {
+        let first_fragment = &left_word[..left_index];
+        let second_fragment = &right_word[right_index..];
+        for input_word in [left_word, right_word] {
+            let Some(first_frag_index) = input_word.find(first_fragment) else {
+                continue;
+            };
+            if input_word[first_frag_index + first_fragment.len()..]
+                .starts_with(second_fragment)
+            {
+                return None;
+            }
+        }
+        Some(format!("{}{}", first_fragment, second_fragment))
+    }
+
+    pub fn portmanteau(left_word: &str, right_word: &str) -> Option<String> {
+        if !(validate(left_word) && validate(right_word)) {
+            return None;
+        }
+
+        portmanteau_by_trios(left_word, right_word).or_else(|| {
+            let left_vowels = vowels_rtl(left_word);
+            let right_vowels = vowels_ltr(right_word);
+
+            let mut chosen_left_vowel_index: Option<usize> = None;
+            let mut chosen_right_vowel_index: Option<usize> = None;
+            for (left_vowel_index, right_vowel_index) in left_vowels
+                .iter()
+                .copied()
+                .zip(right_vowels.iter().copied())
+            {
+                match (left_vowel_index, right_vowel_index) {
+                    (Some(left_vowel_index), Some(right_vowel_index)) => {
+                        let potential_answer = make_if_acceptable(
+                            left_word,
+                            left_vowel_index,
+                            right_word,
+                            right_vowel_index,
+                        );
+                        if potential_answer.is_some() {
+                            return potential_answer;
+                        }
+                        chosen_left_vowel_index = Some(left_vowel_index);
+                        chosen_right_vowel_index = Some(right_vowel_index);
+                    },
+                    (Some(left_index), None) => {
+                        if chosen_left_vowel_index
+                            .is_none_or(|inner| left_index > inner)
+                        {
+                            chosen_left_vowel_index = Some(left_index);
+                        }
+                    },
+                    (None, Some(right_index)) => {
+                        if chosen_right_vowel_index
+                            .is_none_or(|inner| right_index < inner)
+                        {
+                            chosen_right_vowel_index = Some(right_index);
+                        }
+                    },
+                    (None, None) => {},
+                }
+            }
+            chosen_left_vowel_index
+                .zip(chosen_right_vowel_index)
+                .and_then(|(left_vowel_index, right_vowel_index)| {
+                    make_if_acceptable(
+                        left_word,
+                        left_vowel_index,
+                        right_word,
+                        right_vowel_index,
+                    )
+                })
+        })
+    }
+}
+
+/// Every word blended with every other word, which is where preparing each
+/// word once pays off, even counting the time taken to prepare them
+pub fn prepared_words(criterion: &mut Criterion) {
+    let generator = Portmanteau::default();
+    let mut group = criterion.benchmark_group("Cross product");
+    group.sample_size(10);
+
+    let mut words = input_pairs()
+        .into_iter()
+        .flat_map(|(left_word, right_word)| [left_word, right_word])
+        .collect::<Vec<_>>();
+    words.sort_unstable();
+    words.dedup();
+    // The default settings should still make the same portmanteaux
+    assert!(words.iter().all(|left_word| {
+        words.iter().all(|right_word| {
+            portmanteau(left_word, right_word)
+                == baseline::portmanteau(left_word, right_word)
+        })
+    }));
+    group.bench_with_input(
+        BenchmarkId::new("Baseline", words.len()),
+        &words,
+        |bencher, words| {
+            bencher.iter(|| {
+                for left_word in words {
+                    for right_word in words {
+                        baseline::portmanteau(left_word, right_word);
+                    }
+                }
+            });
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("portmanteau()", words.len()),
+        &words,
+        |bencher, words| {
+            bencher.iter(|| {
+                for left_word in words {
+                    for right_word in words {
+                        portmanteau(left_word, right_word);
+                    }
+                }
+            });
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("Unprepared", words.len()),
+        &words,
+        |bencher, words| {
+            bencher.iter(|| {
+                for left_word in words {
+                    for right_word in words {
+                        generator.blend(left_word, right_word);
+                    }
+                }
+            });
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("Prepared", words.len()),
+        &words,
+        |bencher, words| {
+            bencher.iter(|| {
+                let prepared = words
+                    .iter()
+                    .map(|word| generator.prepare(word))
+                    .collect::<Vec<_>>();
+                for left_word in &prepared {
+                    for right_word in &prepared {
+                        generator.blend_prepared(left_word, right_word);
+                    }
+                }
+            });
+        },
+    );
+    group.finish();
+}
+
+criterion_group!(benches, csv_file, shared_trios, prepared_words);
 criterion_main!(benches);
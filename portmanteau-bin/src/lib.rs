@@ -1,6 +1,7 @@
 use std::{error::Error, fmt, io, str::Utf8Error};
 
 use pico_args::Error::Utf8ArgumentParsingFailed;
+use portmanteau::PortmanteauError;
 
 type Result<T> = std::result::Result<T, BinError>;
 
@@ -53,7 +54,9 @@ pub enum BinError {
     BadWordSplit(String), // TODO: use reference?
     BadLineSplit,
     StdinEnd(io::Error),
-    NoneProduced((String, String)), // TODO: use reference?
+    WriteStdout(io::Error),
+    NoneProduced((String, String), PortmanteauError), // TODO: use reference?
+    NoneProducedMany(Vec<String>),
     DecodeStdin(Utf8Error),
 }
 
@@ -65,7 +68,9 @@ impl BinError {
             BadWordSplit(_) => 2,
             BadLineSplit => 2,
             StdinEnd(_) => 3,
-            NoneProduced(_) => 1,
+            WriteStdout(_) => 3,
+            NoneProduced(..) => 1,
+            NoneProducedMany(_) => 1,
             DecodeStdin(_) => 3,
         }
     }
@@ -98,8 +103,23 @@ impl fmt::Display for BinError {
             StdinEnd(io_err) => {
                 write!(f, "STDIN read ended with error ({})", io_err)
             },
-            NoneProduced((a, b)) => {
-                write!(f, "{:?} and {:?} did not produce a portmanteau", a, b)
+            WriteStdout(io_err) => {
+                write!(f, "Failed to write to STDOUT ({})", io_err)
+            },
+            NoneProduced((a, b), reason) => {
+                write!(
+                    f,
+                    "{:?} and {:?} did not produce a portmanteau ({})",
+                    a, b, reason
+                )
+            },
+            NoneProducedMany(words) => {
+                let (last, rest) =
+                    words.split_last().expect("more than two words");
+                for word in rest {
+                    write!(f, "{:?}, ", word)?;
+                }
+                write!(f, "and {:?} did not produce a portmanteau", last)
             },
             DecodeStdin(utf_err) => {
                 write!(f, "Failed to read STDIN with given split ({})", utf_err)
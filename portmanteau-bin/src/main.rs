@@ -1,22 +1,26 @@
 #![forbid(unsafe_code)]
 
-use std::{io, io::BufRead, process};
+use std::{
+    io,
+    io::{BufRead, Write},
+    process,
+};
 
-use portmanteau::portmanteau;
+use portmanteau::{portmanteau_many, try_portmanteau, Blend};
 use portmanteau_bin::{BinError::*, *};
 
 const HELP: &str = "\
 portmanteau
 
 USAGE:
-  portmanteau [OPTIONS] [WORD 1] [WORD 2]           Words to combine given as \
-                    arguments
+  portmanteau [OPTIONS] [WORD 1] [WORD 2]...        Words to combine given as \
+                    arguments (all the words are combined)
   portmanteau [OPTIONS] -                           Words to combine taken \
-                    from STDIN
+                    from STDIN (all the words on a line are combined)
 
 OPTIONS:
   -w [delimiter], --word-split [delimiter]          Specify the string between \
-                    the two words being input
+                    the words being input
   -l [delimiter], --line-split [delimiter]          Specify the character \
                     between each pair of words (STDIN mode only)
   -h, --help                                        Access this help text
@@ -60,16 +64,17 @@ fn app() -> Result<()> {
     if pargs.contains("-") {
         // STDIN mode
         //eprintln!("STDIN mode");
-        io::stdin()
-            .lock()
-            .split(config.line_split as u8)
-            .for_each(|line| {
-                // STDIN mode handles errors line-by-line and just prints them
-                // without aborting
-                if let Err(warning) = stdin_line(&config, line) {
-                    eprintln!("{}", warning);
-                }
-            });
+        let stdout = io::stdout();
+        let mut out = stdout.lock();
+        for line in io::stdin().lock().split(config.line_split as u8) {
+            // STDIN mode handles errors line-by-line and just prints them
+            // without aborting, unless nothing more can be output
+            match stdin_line(&config, line, &mut out) {
+                Err(fatal @ WriteStdout(_)) => return Err(fatal),
+                Err(warning) => eprintln!("{}", warning),
+                Ok(()) => {},
+            }
+        }
     } else {
         // Args mode
         //eprintln!("Args mode");
@@ -78,25 +83,49 @@ fn app() -> Result<()> {
     Ok(())
 }
 
-fn stdin_line(
+fn stdin_line<W: Write>(
     config: &RuntimeConfig,
     io_bytes: io::Result<Vec<u8>>,
+    out: &mut W,
 ) -> Result<()> {
     let bytes = io_bytes?;
     let line = std::str::from_utf8(&bytes)?;
-    let mut words = line.split(&config.word_split);
-    let a = words.next().ok_or(InsufficientArguments(None))?;
-    let b = words.next().ok_or(InsufficientArguments(None))?.trim_end();
-
-    if words.next().is_some() {
-        eprintln!("More words than expected on line");
+    let mut words = line.split(&config.word_split).collect::<Vec<_>>();
+    if let Some(last) = words.last_mut() {
+        *last = last.trim_end();
     }
+    words.retain(|word| !word.is_empty());
+    write_blend(&words, out)
+}
 
-    match portmanteau(a, b) {
-        Some(pm) => println!("{}", pm),
-        None => return Err(NoneProduced((a.to_string(), b.to_string()))),
+/// Blends all the words together, writing the portmanteau on its own line
+fn write_blend<W: Write>(words: &[&str], out: &mut W) -> Result<()> {
+    if words.len() > 2 {
+        // Blend all the words together
+        return match portmanteau_many(words) {
+            Some(pm) => writeln!(out, "{}", pm).map_err(WriteStdout),
+            None => Err(NoneProducedMany(
+                words.iter().copied().map(String::from).collect(),
+            )),
+        };
+    }
+    let (a, b) = match *words {
+        [a, b] => (a, b),
+        _ => return Err(InsufficientArguments(None)),
+    };
+
+    match try_portmanteau(a, b) {
+        Ok(pm) => write_line(out, &pm),
+        Err(why) => Err(NoneProduced((a.to_string(), b.to_string()), why)),
     }
-    Ok(())
+}
+
+/// Writes a portmanteau on its own line, straight from the input words
+/// (without allocating)
+fn write_line<W: Write>(out: &mut W, pm: &Blend) -> Result<()> {
+    pm.write_io(out)
+        .and_then(|()| out.write_all(b"\n"))
+        .map_err(WriteStdout)
 }
 
 fn args_mode(
@@ -105,43 +134,32 @@ fn args_mode(
 ) -> Result<()> {
     let remaining_args = pargs.finish();
 
+    let mut out = io::stdout().lock();
     if config.is_split_whitespace() {
-        // Expect two args
-        if remaining_args.len() > 2 {
-            eprintln!("More words than expected on line");
-        }
-        let a = &remaining_args
-            .get(0)
-            .ok_or(InsufficientArguments(Some(2)))?
-            .to_string_lossy();
-        let b = &remaining_args
-            .get(1)
-            .ok_or(InsufficientArguments(Some(2)))?
-            .to_string_lossy();
-        match portmanteau(a, b) {
-            Some(pm) => println!("{}", pm),
-            None => return Err(NoneProduced((a.to_string(), b.to_string()))),
+        // Expect at least two args
+        if remaining_args.len() < 2 {
+            return Err(InsufficientArguments(Some(2)));
         }
+        let args = remaining_args
+            .iter()
+            .map(|arg| arg.to_string_lossy())
+            .collect::<Vec<_>>();
+        let words = args.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        write_blend(&words, &mut out)?;
     } else {
         // Expect one arg
         if remaining_args.len() > 1 {
             eprintln!("More words than expected on line");
         }
         let s = remaining_args
-            .get(0)
+            .first()
             .ok_or(InsufficientArguments(Some(1)))?
             .to_string_lossy();
-        let mut s_iter = s.split(&config.word_split);
-        let a = s_iter
-            .next()
-            .ok_or(BadWordSplit(config.word_split.clone()))?;
-        let b = s_iter
-            .next()
-            .ok_or(BadWordSplit(config.word_split.clone()))?;
-        match portmanteau(a, b) {
-            Some(pm) => println!("{}", pm),
-            None => return Err(NoneProduced((a.into(), b.into()))),
+        let words = s.split(&config.word_split).collect::<Vec<_>>();
+        if words.len() < 2 {
+            return Err(BadWordSplit(config.word_split.clone()));
         }
+        write_blend(&words, &mut out)?;
     }
     Ok(())
 }
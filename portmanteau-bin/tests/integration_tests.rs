@@ -29,19 +29,19 @@ fn help() {
 #[test]
 fn word_splits() {
     get_bin()
-        .args(&["-w", ".", "liquid.slinky"])
+        .args(["-w", ".", "liquid.slinky"])
         .assert()
         .stdout("liquinky\n")
         .stderr("")
         .success();
     get_bin()
-        .args(&["-w", ".-.", "liquid.-.slinky"])
+        .args(["-w", ".-.", "liquid.-.slinky"])
         .assert()
         .stdout("liquinky\n")
         .stderr("")
         .success();
     get_bin()
-        .args(&["-w", ",", "-"])
+        .args(["-w", ",", "-"])
         .write_stdin("liquid,slinky")
         .assert()
         .stdout("liquinky\n")
@@ -52,13 +52,13 @@ fn word_splits() {
 #[test]
 fn line_splits() {
     get_bin()
-        .args(&["-l", ".", "-"])
+        .args(["-l", ".", "-"])
         .write_stdin("liquid slinky.innovative madlad")
         .assert()
         .stdout("liquinky\ninnovadlad\n")
         .stderr("");
     get_bin()
-        .args(&["-l", "\t", "-"])
+        .args(["-l", "\t", "-"])
         .write_stdin("liquid slinky\tinnovative madlad")
         .assert()
         .stdout("liquinky\ninnovadlad\n")
@@ -68,7 +68,7 @@ fn line_splits() {
 #[test]
 fn bad_line_split() {
     get_bin()
-        .args(&["-l", ",\n", "-"])
+        .args(["-l", ",\n", "-"])
         .write_stdin("liquid slinky,\ninnovative madlad")
         .assert()
         .stdout("")
@@ -79,18 +79,55 @@ fn bad_line_split() {
 #[test]
 fn args_mode() {
     get_bin()
-        .args(&["liquid", "slinky"])
+        .args(["liquid", "slinky"])
         .assert()
         .stdout("liquinky\n")
         .stderr("")
         .success();
     get_bin().arg("liquid").assert().code(2);
+}
+
+#[test]
+fn args_many_words() {
     get_bin()
-        .args(&["liquid", "slinky", "dogs"])
+        .args(["breakfast", "lunch", "dinner"])
         .assert()
-        .stdout("liquinky\n")
-        .stderr("More words than expected on line\n")
+        .stdout("breakfunchinner\n")
+        .stderr("")
+        .success();
+    get_bin()
+        .args(["-w", ".", "breakfast.lunch.dinner"])
+        .assert()
+        .stdout("breakfunchinner\n")
+        .stderr("")
         .success();
+    get_bin()
+        .args(["tin", "can", "opener"])
+        .assert()
+        .stdout("")
+        .stderr(
+            "\"tin\", \"can\", and \"opener\" did not produce a portmanteau\n",
+        )
+        .code(1);
+}
+
+#[test]
+fn stdin_many_words() {
+    get_bin()
+        .arg("-")
+        .write_stdin("basket butter cookie\nliquid slinky")
+        .assert()
+        .stdout("baskuttookie\nliquinky\n")
+        .stderr("")
+        .success();
+    get_bin()
+        .arg("-")
+        .write_stdin("tin can opener")
+        .assert()
+        .stdout("")
+        .stderr(
+            "\"tin\", \"can\", and \"opener\" did not produce a portmanteau\n",
+        );
 }
 
 #[test]
@@ -119,7 +156,7 @@ mod args_mode_errors {
             .stderr("Insufficient arguments provided, expected 2\n")
             .code(2);
         get_bin()
-            .args(&["-w", "."])
+            .args(["-w", "."])
             .assert()
             .stderr("Insufficient arguments provided, expected 1\n")
             .code(2);
@@ -128,7 +165,7 @@ mod args_mode_errors {
     #[test]
     fn bad_word_split() {
         get_bin()
-            .args(&["-w", ",", "liquidslinky"])
+            .args(["-w", ",", "liquidslinky"])
             .assert()
             .stderr("Split \",\" failed to produce at least two parts\n")
             .code(2);
@@ -137,14 +174,40 @@ mod args_mode_errors {
     #[test]
     fn none_produced() {
         get_bin()
-            .args(&["wet", "dog"])
+            .args(["wet", "dog"])
+            .assert()
+            .stderr(
+                "\"wet\" and \"dog\" did not produce a portmanteau (\"wet\" \
+                 is shorter than 4 letters)\n",
+            )
+            .code(1);
+        get_bin()
+            .args(["-w", ".", "wet.dog"])
+            .assert()
+            .stderr(
+                "\"wet\" and \"dog\" did not produce a portmanteau (\"wet\" \
+                 is shorter than 4 letters)\n",
+            )
+            .code(1);
+    }
+
+    #[test]
+    fn none_produced_reason() {
+        get_bin()
+            .args(["swords", "words"])
             .assert()
-            .stderr("\"wet\" and \"dog\" did not produce a portmanteau\n")
+            .stderr(
+                "\"swords\" and \"words\" did not produce a portmanteau \
+                 (every portmanteau found was part of an input word)\n",
+            )
             .code(1);
         get_bin()
-            .args(&["-w", ".", "wet.dog"])
+            .args(["rhythm", "crypt"])
             .assert()
-            .stderr("\"wet\" and \"dog\" did not produce a portmanteau\n")
+            .stderr(
+                "\"rhythm\" and \"crypt\" did not produce a portmanteau \
+                 (\"rhythm\" has no vowels to join on)\n",
+            )
             .code(1);
     }
 }
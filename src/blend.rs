@@ -0,0 +1,406 @@
+use core::{
+    char::{ToLowercase, ToUppercase},
+    fmt,
+};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::has_capitals;
+
+/// How the join point of a [`Blend`] was found
+///
+/// The built-in methods are listed in the order they're tried by default
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum JoinMethod {
+    /// Both words share a run of letters (by default three, a trio), and the
+    /// blend switches from one word to the other on it
+    SharedTrio,
+    /// The words are joined on the same vowel
+    MatchingVowel,
+    /// The rightmost vowel of the left word is joined to the leftmost vowel of
+    /// the right word
+    FallbackVowel,
+    /// The words are joined where two syllables meet (not tried by default,
+    /// see [`Syllables`](crate::Syllables))
+    SyllableBoundary,
+    /// The words share a sound (or a similar vowel sound), which may be
+    /// spelled differently in each (not tried by default, see
+    /// [`Phonemes`](crate::Phonemes))
+    SharedSound,
+    /// One word is kept whole, and joined to the other where its syllables
+    /// meet (not tried by default, see
+    /// [`PortmanteauBuilder::embed_words`](crate::PortmanteauBuilder::embed_words))
+    WholeWord,
+    /// Both words are kept whole, with any letters the end of the left word
+    /// shares with the start of the right word only written once (not tried
+    /// by default, see
+    /// [`PortmanteauBuilder::compound_fallback`](crate::PortmanteauBuilder::compound_fallback))
+    Compound,
+    /// Made by a [`Strategy`](crate::Strategy) from outside this crate, with
+    /// the description it gave
+    Custom(&'static str),
+}
+
+/// Which way round two words were blended, see
+/// [`blend_either_order`](crate::blend_either_order)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum WordOrder {
+    /// The first word given is on the left
+    Given,
+    /// The second word given is on the left
+    Swapped,
+}
+
+/// How a [`Blend`] is capitalised
+///
+/// Words are blended regardless of case, then the capitalisation is chosen
+/// from the input words:
+///
+/// * If both words are in capitals, so is the portmanteau
+/// * If either word has capitals other than its first letter, but isn't all in
+///   capitals (e.g. "iPhone" or "McDonald"), each letter is kept as it is in
+///   its word
+/// * If the left word starts with a capital, the portmanteau is in title case
+/// * Otherwise, the portmanteau is in lowercase, or title case when blending
+///   names (see
+///   [`PortmanteauBuilder::names`](crate::PortmanteauBuilder::names))
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{blend, Casing};
+///
+/// let netflill = blend("Netflix", "chill").unwrap();
+/// assert_eq!(netflill.casing(), Casing::Title);
+/// assert_eq!(netflill.to_string(), "Netflill");
+///
+/// let netflill = blend("NETFLIX", "CHILL").unwrap();
+/// assert_eq!(netflill.casing(), Casing::Upper);
+/// assert_eq!(netflill.to_string(), "NETFLILL");
+///
+/// let iphoid = blend("iPhone", "Android").unwrap();
+/// assert_eq!(iphoid.casing(), Casing::Mixed);
+/// assert_eq!(iphoid.to_string(), "iPhoid");
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Casing {
+    /// all lowercase
+    Lower,
+    /// First letter capitalised, the rest lowercase (except after a hyphen
+    /// or apostrophe in a name, e.g. "Mary-Kate" or "O'Brien")
+    Title,
+    /// ALL CAPITALS
+    Upper,
+    /// Each letter as it is in the word it came from
+    Mixed,
+}
+
+impl Casing {
+    /// The casing for a portmanteau of several words, the first being the
+    /// leftmost
+    pub(crate) fn of_words(words: &[&str]) -> Self {
+        fn is_upper(word: &str) -> bool {
+            has_capitals(word) && !word.chars().any(char::is_lowercase)
+        }
+
+        // Capitals after the first letter (or part of a name), with some
+        // lowercase letters too. Letters without a capital of their own (e.g.
+        // "ß") are often left lowercase in capitals, so don't count
+        fn is_mixed(word: &str) -> bool {
+            let mut starts_word = true;
+            has_capitals(word)
+                && word.chars().any(|c| {
+                    let inner_capital = c.is_uppercase() && !starts_word;
+                    starts_word = matches!(c, '-' | '\'');
+                    inner_capital
+                })
+                && word
+                    .chars()
+                    .any(|c| c.is_lowercase() && c.to_uppercase().len() == 1)
+        }
+
+        if words.iter().all(|word| is_upper(word)) {
+            Casing::Upper
+        } else if words.iter().any(|word| is_mixed(word)) {
+            Casing::Mixed
+        } else if words
+            .first()
+            .and_then(|word| word.chars().next())
+            .is_some_and(char::is_uppercase)
+        {
+            Casing::Title
+        } else {
+            Casing::Lower
+        }
+    }
+
+    /// Capitalises the characters of a portmanteau
+    pub(crate) fn apply<I: Iterator<Item = char>>(
+        self,
+        chars: I,
+    ) -> impl Iterator<Item = char> {
+        // Whether the next letter starts a word (or part of a name)
+        let mut starts_word = true;
+        chars.flat_map(move |c| {
+            let capital = match self {
+                Casing::Upper => Some(true),
+                Casing::Title => Some(starts_word),
+                Casing::Lower => Some(false),
+                Casing::Mixed => None,
+            };
+            starts_word = matches!(c, '-' | '\'');
+            match capital {
+                Some(true) => CaseMapped::Upper(c.to_uppercase()),
+                Some(false) => CaseMapped::Lower(c.to_lowercase()),
+                None => CaseMapped::Kept(Some(c)),
+            }
+        })
+    }
+}
+
+/// A portmanteau, and how it was made
+///
+/// A `Blend` is the start of the left word (up to, but not including,
+/// [`Blend::left_index`]) followed by the end of the right word (from
+/// [`Blend::right_index`] onwards), capitalised according to its
+/// [`Casing`]. It borrows from the input words rather than holding a copy of
+/// the portmanteau, which can be written out without allocating (see
+/// [`Blend::write_to`] and [`Blend::write_io`], or use `{}` formatting). Use
+/// [`to_string`](alloc::string::ToString::to_string) to get the portmanteau
+/// itself
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{blend, JoinMethod};
+///
+/// let liquinky = blend("liquid", "slinky").unwrap();
+/// assert_eq!(liquinky.left_fragment(), "liqu");
+/// assert_eq!(liquinky.right_fragment(), "inky");
+/// assert_eq!(liquinky.method(), JoinMethod::MatchingVowel);
+/// assert_eq!(liquinky.to_string(), "liquinky");
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Blend<'a> {
+    left_word: &'a str,
+    left_index: usize,
+    right_word: &'a str,
+    right_index: usize,
+    method: JoinMethod,
+    casing: Casing,
+}
+
+impl<'a> Blend<'a> {
+    pub(crate) fn new(
+        left_word: &'a str,
+        left_index: usize,
+        right_word: &'a str,
+        right_index: usize,
+        method: JoinMethod,
+    ) -> Self {
+        Blend {
+            left_word,
+            left_index,
+            right_word,
+            right_index,
+            method,
+            casing: Casing::Lower,
+        }
+    }
+
+    pub(crate) fn with_casing(mut self, casing: Casing) -> Self {
+        self.casing = casing;
+        self
+    }
+
+    /// The word the portmanteau starts with
+    pub fn left_word(&self) -> &'a str {
+        self.left_word
+    }
+
+    /// The word the portmanteau ends with
+    pub fn right_word(&self) -> &'a str {
+        self.right_word
+    }
+
+    /// Where the left word is cut (the byte index of the first character not
+    /// kept)
+    pub fn left_index(&self) -> usize {
+        self.left_index
+    }
+
+    /// Where the right word is cut (the byte index of the first character
+    /// kept)
+    pub fn right_index(&self) -> usize {
+        self.right_index
+    }
+
+    /// The part of the left word that is kept, as it was given (i.e. without
+    /// the casing applied)
+    pub fn left_fragment(&self) -> &'a str {
+        &self.left_word[..self.left_index]
+    }
+
+    /// The part of the right word that is kept, as it was given (i.e. without
+    /// the casing applied)
+    pub fn right_fragment(&self) -> &'a str {
+        &self.right_word[self.right_index..]
+    }
+
+    /// How the join point was found
+    pub fn method(&self) -> JoinMethod {
+        self.method
+    }
+
+    /// How the portmanteau is capitalised
+    pub fn casing(&self) -> Casing {
+        self.casing
+    }
+
+    /// The length of the portmanteau in bytes
+    pub fn len(&self) -> usize {
+        if self.is_verbatim() {
+            self.left_index + self.right_word.len() - self.right_index
+        } else {
+            self.chars().map(char::len_utf8).sum()
+        }
+    }
+
+    /// Whether the portmanteau is empty (it never is when produced by this
+    /// crate)
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the characters of the portmanteau
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        self.casing.apply(
+            self.left_fragment()
+                .chars()
+                .chain(self.right_fragment().chars()),
+        )
+    }
+
+    /// Writes the portmanteau to a string buffer (or anything else that
+    /// implements [`fmt::Write`]), without allocating
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::blend;
+    ///
+    /// let mut buffer = String::from("liquid + slinky = ");
+    /// blend("liquid", "slinky").unwrap().write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, "liquid + slinky = liquinky");
+    /// ```
+    pub fn write_to<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        if self.is_verbatim() {
+            out.write_str(self.left_fragment())?;
+            out.write_str(self.right_fragment())
+        } else {
+            self.chars().try_for_each(|c| out.write_char(c))
+        }
+    }
+
+    /// Writes the portmanteau as UTF-8 to a byte stream (or anything else
+    /// that implements [`io::Write`]), without allocating
+    ///
+    /// This needs the `std` feature
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::blend;
+    ///
+    /// let mut buffer = [0; 16];
+    /// let mut unused = &mut buffer[..];
+    /// blend("Liquid", "Slinky").unwrap().write_io(&mut unused).unwrap();
+    /// let written = 16 - unused.len();
+    /// assert_eq!(&buffer[..written], b"Liquinky");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_io<W: io::Write + ?Sized>(
+        &self,
+        out: &mut W,
+    ) -> io::Result<()> {
+        if self.is_verbatim() {
+            out.write_all(self.left_fragment().as_bytes())?;
+            return out.write_all(self.right_fragment().as_bytes());
+        }
+
+        // Capitalised letters are gathered into batches to be written
+        let mut batch = [0; 64];
+        let mut used = 0;
+        for c in self.chars() {
+            if used + c.len_utf8() > batch.len() {
+                out.write_all(&batch[..used])?;
+                used = 0;
+            }
+            used += c.encode_utf8(&mut batch[used..]).len();
+        }
+        out.write_all(&batch[..used])
+    }
+
+    /// Whether the fragments can be written out as they are, i.e. the casing
+    /// wouldn't change them
+    fn is_verbatim(&self) -> bool {
+        match self.casing {
+            Casing::Lower => {
+                !has_capitals(self.left_fragment())
+                    && !has_capitals(self.right_fragment())
+            },
+            Casing::Mixed => true,
+            Casing::Title | Casing::Upper => false,
+        }
+    }
+
+    /// Whether two blends spell the same word, regardless of how they were
+    /// made
+    pub(crate) fn same_word(&self, other: &Blend) -> bool {
+        self.len() == other.len() && self.chars().eq(other.chars())
+    }
+}
+
+impl fmt::Display for Blend<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+impl PartialEq<str> for Blend<'_> {
+    fn eq(&self, other: &str) -> bool {
+        if self.is_verbatim() {
+            other.len() == self.len()
+                && other.starts_with(self.left_fragment())
+                && other.ends_with(self.right_fragment())
+        } else {
+            self.chars().eq(other.chars())
+        }
+    }
+}
+
+impl PartialEq<&str> for Blend<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// A character changed to upper or lowercase, which can be more than one
+/// character (e.g. "ß" in capitals is "SS"), or left as it is
+enum CaseMapped {
+    Upper(ToUppercase),
+    Lower(ToLowercase),
+    Kept(Option<char>),
+}
+
+impl Iterator for CaseMapped {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            CaseMapped::Upper(chars) => chars.next(),
+            CaseMapped::Lower(chars) => chars.next(),
+            CaseMapped::Kept(c) => c.take(),
+        }
+    }
+}
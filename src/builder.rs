@@ -0,0 +1,472 @@
+use alloc::{string::String, sync::Arc, vec, vec::Vec};
+use core::ops::{Bound, RangeBounds};
+
+use crate::{
+    ExistingWords, FallbackVowels, LanguageProfile, Lexicon, MatchingVowels,
+    NameToken, Overlap, Portmanteau, Scorer, SharedTrios, Strategy,
+    WeightedScorer,
+};
+
+const MIN_WORD_SIZE: usize = 4;
+const MIN_NAME_SIZE: usize = 3;
+const MATCHING_VOWEL_SEARCH_MARGIN: usize = 1;
+
+/// Configures and creates a [`Portmanteau`] generator
+///
+/// Any setting that isn't changed keeps the value used by the
+/// [`portmanteau`](crate::portmanteau) function
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::PortmanteauBuilder;
+///
+/// let generator = PortmanteauBuilder::new()
+///     .min_word_size(5)
+///     .vowels(['a', 'e', 'i', 'o', 'u', 'y'])
+///     .build();
+/// assert_eq!(generator.generate("tiny", "words"), None);
+/// assert_eq!(
+///     generator.generate("rhythm", "music"),
+///     Some("rhusic".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct PortmanteauBuilder {
+    min_word_size: usize,
+    vowel_search_margin: usize,
+    language: LanguageProfile,
+    overlap: Overlap,
+    strategies: Vec<Arc<dyn Strategy>>,
+    check_pronounceability: bool,
+    scorer: Arc<dyn Scorer>,
+    lexicon: Option<Arc<dyn Lexicon>>,
+    existing_words: ExistingWords,
+    names: Option<NameToken>,
+    either_order: bool,
+    min_kept_letters: usize,
+    min_kept_fraction: f64,
+    output_length: (Bound<usize>, Bound<usize>),
+    reject_substrings: bool,
+    embed_words: bool,
+    compound_fallback: bool,
+}
+
+impl PortmanteauBuilder {
+    /// Creates a builder with the default settings
+    pub fn new() -> Self {
+        PortmanteauBuilder {
+            min_word_size: MIN_WORD_SIZE,
+            vowel_search_margin: MATCHING_VOWEL_SEARCH_MARGIN,
+            language: LanguageProfile::english(),
+            overlap: Overlap::default(),
+            strategies: vec![
+                Arc::new(SharedTrios),
+                Arc::new(MatchingVowels),
+                Arc::new(FallbackVowels),
+            ],
+            check_pronounceability: false,
+            scorer: Arc::new(WeightedScorer::default()),
+            lexicon: None,
+            existing_words: ExistingWords::default(),
+            names: None,
+            either_order: false,
+            min_kept_letters: 0,
+            min_kept_fraction: 0.0,
+            output_length: (Bound::Unbounded, Bound::Unbounded),
+            reject_substrings: true,
+            embed_words: false,
+            compound_fallback: false,
+        }
+    }
+
+    /// The minimum length either input word can be (default: 4)
+    pub fn min_word_size(mut self, min_word_size: usize) -> Self {
+        self.min_word_size = min_word_size;
+        self
+    }
+
+    /// How many letters at the start of the left word and end of the right
+    /// word are ignored when searching for vowels to join on (default: 1)
+    pub fn vowel_search_margin(mut self, vowel_search_margin: usize) -> Self {
+        self.vowel_search_margin = vowel_search_margin;
+        self
+    }
+
+    /// The letters treated as vowels (default: a, e, i, o, u)
+    ///
+    /// When joining on vowels, matching vowels are tried in the order given
+    /// here. This only replaces the vowels of the language profile, see
+    /// [`LanguageProfile::with_vowels`]
+    pub fn vowels<I: IntoIterator<Item = char>>(mut self, vowels: I) -> Self {
+        self.language = self
+            .language
+            .with_vowels(vowels.into_iter().map(String::from));
+        self
+    }
+
+    /// The vowel rules to use (default: [`LanguageProfile::english`])
+    pub fn language(mut self, language: LanguageProfile) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// How many letters the words must share to be joined by overlap
+    /// (default: 3)
+    ///
+    /// This is the same as `.overlap(Overlap::Fixed(overlap_length))`
+    pub fn overlap_length(self, overlap_length: usize) -> Self {
+        self.overlap(Overlap::Fixed(overlap_length))
+    }
+
+    /// How many letters the words must share to be joined by overlap, which
+    /// can depend on the words (default: `Overlap::Fixed(3)`)
+    pub fn overlap(mut self, overlap: Overlap) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Adds a strategy to the end of the list of strategies to try
+    ///
+    /// By default, the list is [`SharedTrios`], [`MatchingVowels`], then
+    /// [`FallbackVowels`]. Use [`PortmanteauBuilder::clear_strategies`] first
+    /// if you want to remove or reorder these
+    pub fn strategy<S: Strategy + 'static>(mut self, strategy: S) -> Self {
+        self.strategies.push(Arc::new(strategy));
+        self
+    }
+
+    /// Removes all the strategies, including the defaults
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::{MatchingVowels, Portmanteau, SharedTrios};
+    ///
+    /// // Only join on trios
+    /// let generator = Portmanteau::builder()
+    ///     .clear_strategies()
+    ///     .strategy(SharedTrios)
+    ///     .build();
+    /// assert_eq!(generator.generate("liquid", "slinky"), None);
+    /// ```
+    pub fn clear_strategies(mut self) -> Self {
+        self.strategies.clear();
+        self
+    }
+
+    /// Whether to reject portmanteaus where the join makes a consonant cluster
+    /// or a run of vowels that can't be said, according to the language's
+    /// onsets and codas (default: false)
+    ///
+    /// A cluster is allowed if it's in either input word, or it can be split
+    /// into a coda and an onset, see [`LanguageProfile::with_clusters`]. A run
+    /// of more than two vowels is only allowed if it's in either input word
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::Portmanteau;
+    ///
+    /// assert_eq!(
+    ///     Portmanteau::default().generate("between", "through").unwrap(),
+    ///     "betweough"
+    /// );
+    ///
+    /// let checked = Portmanteau::builder().check_pronounceability(true).build();
+    /// assert_eq!(checked.generate("between", "through"), None);
+    /// ```
+    pub fn check_pronounceability(mut self, check: bool) -> Self {
+        self.check_pronounceability = check;
+        self
+    }
+
+    /// How portmanteaus are rated by [`Portmanteau::best_blend`] (default:
+    /// [`WeightedScorer::default`])
+    pub fn scorer<S: Scorer + 'static>(mut self, scorer: S) -> Self {
+        self.scorer = Arc::new(scorer);
+        self
+    }
+
+    /// Words that already exist, which portmanteaus are checked against
+    /// (default: none)
+    ///
+    /// See [`PortmanteauBuilder::existing_words`] for what happens to
+    /// portmanteaus that are in the lexicon
+    pub fn lexicon<L: Lexicon + 'static>(mut self, lexicon: L) -> Self {
+        self.lexicon = Some(Arc::new(lexicon));
+        self
+    }
+
+    /// What to do with portmanteaus that are already in the lexicon (default:
+    /// [`ExistingWords::Reject`])
+    ///
+    /// This has no effect without a lexicon
+    pub fn existing_words(mut self, existing_words: ExistingWords) -> Self {
+        self.existing_words = existing_words;
+        self
+    }
+
+    /// The fewest letters of each input word a portmanteau must keep (default:
+    /// 0)
+    ///
+    /// Letters the words share where they're joined count towards both words,
+    /// e.g. "mototel" keeps "moto" of "motor" (its second "o" is also the first
+    /// of "otel") and "otel" of "hotel". Joins that keep too little are
+    /// skipped, so the next join is tried instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::{portmanteau, Portmanteau};
+    ///
+    /// // Only keeps "ade" of "comrade"
+    /// assert_eq!(portmanteau("african", "comrade").unwrap(), "africade");
+    /// let generator = Portmanteau::builder().min_kept_letters(4).build();
+    /// assert_eq!(
+    ///     generator.generate("african", "comrade").unwrap(),
+    ///     "africomrade"
+    /// );
+    /// ```
+    pub fn min_kept_letters(mut self, letters: usize) -> Self {
+        self.min_kept_letters = letters;
+        self
+    }
+
+    /// The smallest fraction (between 0 and 1) of each input word's letters a
+    /// portmanteau must keep (default: 0)
+    ///
+    /// This is counted the same way as
+    /// [`PortmanteauBuilder::min_kept_letters`]
+    pub fn min_kept_fraction(mut self, fraction: f64) -> Self {
+        self.min_kept_fraction = fraction;
+        self
+    }
+
+    /// How many letters a portmanteau can have (default: any number)
+    ///
+    /// Portmanteaus that are too short or too long are skipped, so the next
+    /// join is tried instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::{portmanteau, Portmanteau};
+    ///
+    /// assert_eq!(portmanteau("acceptable", "captain").unwrap(), "acceptain");
+    /// let generator = Portmanteau::builder().output_length(10..).build();
+    /// assert_eq!(
+    ///     generator.generate("acceptable", "captain").unwrap(),
+    ///     "acceptaptain"
+    /// );
+    /// ```
+    pub fn output_length<R: RangeBounds<usize>>(mut self, length: R) -> Self {
+        self.output_length =
+            (length.start_bound().cloned(), length.end_bound().cloned());
+        self
+    }
+
+    /// Whether to reject portmanteaus that are part of an input word, e.g.
+    /// "ball" from "basket" and "football" (default: true)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::Portmanteau;
+    ///
+    /// let generator = Portmanteau::builder().reject_substrings(false).build();
+    /// assert_eq!(generator.generate("basket", "football").unwrap(), "ball");
+    /// ```
+    pub fn reject_substrings(mut self, reject: bool) -> Self {
+        self.reject_substrings = reject;
+        self
+    }
+
+    /// Whether to also try portmanteaus that keep one word whole, joined to
+    /// the other word where its syllables meet (default: false)
+    ///
+    /// These are tried after every strategy's join points: first the whole
+    /// left word, followed by as much of the right word as possible, then as
+    /// much of the left word as possible, followed by the whole right word.
+    /// Syllables are split using the generator's language (see
+    /// [`Syllables`](crate::Syllables)), and the portmanteaus are made with
+    /// [`JoinMethod::WholeWord`](crate::JoinMethod::WholeWord).
+    /// [`WeightedScorer`] scores portmanteaus that keep a word whole higher
+    /// when this is on, so [`Portmanteau::best_blend`] prefers them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::{JoinMethod, Portmanteau};
+    ///
+    /// let generator = Portmanteau::builder().embed_words(true).build();
+    /// let blend = generator.best_blend("basket", "football").unwrap();
+    /// assert_eq!(blend, "basketball");
+    /// assert_eq!(blend.method(), JoinMethod::WholeWord);
+    ///
+    /// assert_eq!(generator.blend("sports", "broadcast").unwrap(), "spoadcast");
+    /// assert_eq!(
+    ///     generator.best_blend("sports", "broadcast").unwrap(),
+    ///     "sportscast"
+    /// );
+    /// ```
+    pub fn embed_words(mut self, embed: bool) -> Self {
+        self.embed_words = embed;
+        self
+    }
+
+    /// Whether to join the words whole if nothing else works (default:
+    /// false)
+    ///
+    /// The left word is followed by the right word, without any letters the
+    /// start of the right word shares with the end of the left word. This is
+    /// tried last, isn't checked for pronounceability (as both words are said
+    /// in full), and is made with
+    /// [`JoinMethod::Compound`](crate::JoinMethod::Compound)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::{portmanteau, JoinMethod, Portmanteau};
+    ///
+    /// assert_eq!(portmanteau("sport", "transport"), None);
+    /// let generator = Portmanteau::builder().compound_fallback(true).build();
+    /// let blend = generator.blend("sport", "transport").unwrap();
+    /// assert_eq!(blend, "sportransport");
+    /// assert_eq!(blend.method(), JoinMethod::Compound);
+    /// ```
+    pub fn compound_fallback(mut self, fallback: bool) -> Self {
+        self.compound_fallback = fallback;
+        self
+    }
+
+    /// Whether to also try blending the words the other way round, and use
+    /// whichever portmanteau scores higher (default: false)
+    ///
+    /// This applies to [`Portmanteau::blend`] (and the methods like it) and
+    /// [`Portmanteau::best_blend`], but not [`Portmanteau::candidates`]. See
+    /// [`blend_either_order`](crate::blend_either_order) to find out which way
+    /// round the words were blended
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::Portmanteau;
+    ///
+    /// let generator = Portmanteau::builder().either_order(true).build();
+    /// assert_eq!(generator.generate("itches", "crutch").unwrap(), "crutches");
+    /// ```
+    pub fn either_order(mut self, either_order: bool) -> Self {
+        self.either_order = either_order;
+        self
+    }
+
+    /// Blends names (or other phrases) rather than single words (default:
+    /// off)
+    ///
+    /// Each input is split into words on whitespace, and only the chosen word
+    /// is blended, so the [`Blend`](crate::Blend) made is of those words.
+    /// Portmanteaus of names are always capitalised (see
+    /// [`Casing`](crate::Casing)). See [`PortmanteauBuilder::for_names`] for
+    /// the other settings suited to names
+    pub fn names(mut self, token: NameToken) -> Self {
+        self.names = Some(token);
+        self
+    }
+
+    /// Changes the settings to suit blending people's names, e.g. "Brad Pitt"
+    /// and "Angelina Jolie"
+    ///
+    /// This blends first names (see [`PortmanteauBuilder::names`]), and allows
+    /// names as short as 3 letters. As short names often have nowhere else to
+    /// be joined, one name can be kept whole (see
+    /// [`PortmanteauBuilder::embed_words`]) or the names can be put together
+    /// (see [`PortmanteauBuilder::compound_fallback`])
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::{Casing, Portmanteau};
+    ///
+    /// let generator = Portmanteau::builder().for_names().build();
+    /// let blend = generator.blend("ben affleck", "jennifer lopez").unwrap();
+    /// assert_eq!(blend, "Bennifer");
+    /// assert_eq!(blend.casing(), Casing::Title);
+    /// ```
+    pub fn for_names(self) -> Self {
+        self.min_word_size(MIN_NAME_SIZE)
+            .names(NameToken::First)
+            .embed_words(true)
+            .compound_fallback(true)
+    }
+
+    /// Creates the generator
+    ///
+    /// # Panics
+    ///
+    /// If there are no strategies, the overlap length is 0, the minimum
+    /// fraction kept isn't between 0 and 1, or the settings could ask for more
+    /// letters than the shortest allowed word has (i.e. the minimum word size
+    /// is less than the overlap length, or not more than the vowel search
+    /// margin)
+    pub fn build(self) -> Portmanteau {
+        assert!(
+            !self.strategies.is_empty(),
+            "At least one strategy is needed"
+        );
+        let overlap_length = self.overlap.min_length();
+        assert!(overlap_length > 0, "Overlap length must be at least 1");
+        assert!(
+            self.min_word_size >= overlap_length,
+            "Minimum word size ({}) must be at least the overlap length ({})",
+            self.min_word_size,
+            overlap_length
+        );
+        assert!(
+            self.min_word_size > self.vowel_search_margin,
+            "Minimum word size ({}) must be more than the vowel search margin \
+             ({})",
+            self.min_word_size,
+            self.vowel_search_margin
+        );
+        assert!(
+            (0.0..=1.0).contains(&self.min_kept_fraction),
+            "Minimum fraction kept ({}) must be between 0 and 1",
+            self.min_kept_fraction
+        );
+        let vowels = self
+            .language
+            .all_vowels()
+            .filter_map(|(vowel, _)| {
+                let mut letters = vowel.chars();
+                letters.next().filter(|_| letters.next().is_none())
+            })
+            .collect();
+        Portmanteau {
+            min_word_size: self.min_word_size,
+            vowel_search_margin: self.vowel_search_margin,
+            language: self.language,
+            vowels,
+            overlap: self.overlap,
+            strategies: self.strategies,
+            check_pronounceability: self.check_pronounceability,
+            scorer: self.scorer,
+            lexicon: self.lexicon,
+            existing_words: self.existing_words,
+            names: self.names,
+            either_order: self.either_order,
+            min_kept_letters: self.min_kept_letters,
+            min_kept_fraction: self.min_kept_fraction,
+            output_length: self.output_length,
+            reject_substrings: self.reject_substrings,
+            embed_words: self.embed_words,
+            compound_fallback: self.compound_fallback,
+        }
+    }
+}
+
+impl Default for PortmanteauBuilder {
+    fn default() -> Self {
+        PortmanteauBuilder::new()
+    }
+}
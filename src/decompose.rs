@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use crate::{fold_case, Blend, Lexicon, Portmanteau};
+
+impl Portmanteau {
+    /// Finds the pairs of words from a lexicon that this generator would blend
+    /// into the given word, best first
+    ///
+    /// If the generator tries
+    /// [either order](crate::PortmanteauBuilder::either_order), a pair is only
+    /// found in the order the generator would put the words in
+    ///
+    /// See [`decompose`](crate::decompose) for more details
+    pub fn decompose<'l, L: Lexicon + ?Sized>(
+        &self,
+        word: &str,
+        lexicon: &'l L,
+    ) -> Vec<Blend<'l>> {
+        let target = fold_case(word);
+
+        // Words that start the same way as the target could be the left word,
+        // and words that end the same way could be the right word. Each is
+        // kept with how many bytes it shares with the target
+        let mut left_words = Vec::new();
+        let mut right_words = Vec::new();
+        for source in lexicon.words() {
+            if source == target {
+                continue;
+            }
+            let start = shared_bytes(source.chars(), target.chars());
+            let end = shared_bytes(source.chars().rev(), target.chars().rev());
+            if start == 0 && end == 0 {
+                continue;
+            }
+            let prepared = self.prepare(source);
+            if !prepared.is_valid() {
+                continue;
+            }
+            if start > 0 {
+                left_words.push((start, prepared.clone()));
+            }
+            if end > 0 {
+                right_words.push((end, prepared));
+            }
+        }
+        // Most shared first, so the search for a left word can stop early
+        right_words.sort_by_key(|(end, _)| Reverse(*end));
+
+        let mut sources = Vec::new();
+        for (start, left_word) in &left_words {
+            // Between them, the words must cover the whole target
+            for (_, right_word) in right_words
+                .iter()
+                .take_while(|(end, _)| start + end >= target.len())
+            {
+                let Some(blend) = self.blend_prepared(left_word, right_word)
+                else {
+                    continue;
+                };
+                // If trying either order swapped the words, the blend is
+                // found when they're the other way round
+                if blend.left_word() != left_word.word() {
+                    continue;
+                }
+                let left_fragment = blend.left_fragment();
+                let right_fragment = blend.right_fragment();
+                if left_fragment.len() + right_fragment.len() == target.len()
+                    && target.starts_with(left_fragment)
+                    && target.ends_with(right_fragment)
+                {
+                    sources.push((self.score(&blend), blend));
+                }
+            }
+        }
+        // Stable, so ties keep the order of the lexicon
+        sources.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+        sources.into_iter().map(|(_, blend)| blend).collect()
+    }
+}
+
+/// How many bytes two sequences of letters have in common at the start
+fn shared_bytes<I, J>(letters: I, others: J) -> usize
+where
+    I: Iterator<Item = char>,
+    J: Iterator<Item = char>,
+{
+    letters
+        .zip(others)
+        .take_while(|(a, b)| a == b)
+        .map(|(letter, _)| letter.len_utf8())
+        .sum()
+}
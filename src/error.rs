@@ -0,0 +1,143 @@
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Why no portmanteau could be made from two words
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum PortmanteauError {
+    /// An input word has fewer letters than the minimum word size
+    TooShort {
+        /// The offending word
+        word: String,
+        /// The minimum word size of the generator
+        min_word_size: usize,
+    },
+    /// An input word contains something other than letters (e.g. punctuation
+    /// or spaces), other than a hyphen or apostrophe between the letters of a
+    /// name
+    InvalidCharacter {
+        /// The offending word
+        word: String,
+        /// The first character that isn't allowed
+        character: char,
+    },
+    /// Nothing was found to join the words on, and one of them has no vowels
+    /// to join on (the first letter of the left word and last letter of the
+    /// right word aren't searched)
+    NoVowels {
+        /// The word without vowels
+        word: String,
+    },
+    /// Every portmanteau found was rejected for being part of one of the
+    /// input words
+    AllRejected,
+    /// None of the generator's strategies found anywhere to join the words,
+    /// so there was no portmanteau to reject
+    NoJoinPoints,
+    /// Every portmanteau found that wasn't part of an input word was rejected
+    /// for joining the words with consonants that can't be said together
+    Unpronounceable,
+    /// Every portmanteau found that wasn't part of an input word was rejected
+    /// for already being a word in the generator's lexicon
+    AllExistingWords,
+    /// Every portmanteau found that wasn't rejected for another reason kept
+    /// too little of an input word, or had too few or too many letters (see
+    /// [`PortmanteauBuilder::min_kept_letters`](crate::PortmanteauBuilder::min_kept_letters))
+    OutsideLimits,
+}
+
+impl fmt::Display for PortmanteauError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PortmanteauError::*;
+        match self {
+            TooShort {
+                word,
+                min_word_size,
+            } => {
+                write!(
+                    f,
+                    "{:?} is shorter than {} letters",
+                    word, min_word_size
+                )
+            },
+            InvalidCharacter { word, character } => write!(
+                f,
+                "{:?} contains {:?}, only letters are allowed",
+                word, character
+            ),
+            NoVowels { word } => {
+                write!(f, "{:?} has no vowels to join on", word)
+            },
+            AllRejected => {
+                write!(f, "every portmanteau found was part of an input word")
+            },
+            NoJoinPoints => {
+                write!(f, "no way was found to join the words")
+            },
+            Unpronounceable => {
+                write!(f, "every portmanteau found was unpronounceable")
+            },
+            AllExistingWords => {
+                write!(f, "every portmanteau found is already a word")
+            },
+            OutsideLimits => write!(
+                f,
+                "every portmanteau found kept too little of a word, or was \
+                 too short or too long"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for PortmanteauError {}
+
+/// Which checks the join points tried were rejected by, to explain why no
+/// portmanteau was made
+#[derive(Debug, Default)]
+pub(crate) struct Rejections {
+    /// Whether any join point was on character boundaries, so was checked
+    pub(crate) join_points: bool,
+    /// Whether any join point was only rejected for being unpronounceable
+    unpronounceable: bool,
+    /// Whether any join point was only rejected for being outside the limits
+    outside_limits: bool,
+    /// Whether any join point was only rejected for being an existing word
+    existing_word: bool,
+}
+
+impl Rejections {
+    /// Notes which checks a join point failed, and gives whether it passed
+    /// them all
+    pub(crate) fn note(
+        &mut self,
+        substring: bool,
+        unpronounceable: bool,
+        outside_limits: bool,
+        existing_word: bool,
+    ) -> bool {
+        self.join_points = true;
+        match (substring, unpronounceable, outside_limits, existing_word) {
+            (false, false, false, false) => return true,
+            (false, true, false, false) => self.unpronounceable = true,
+            (false, false, true, false) => self.outside_limits = true,
+            (false, false, false, true) => self.existing_word = true,
+            _ => {},
+        }
+        false
+    }
+
+    /// Why every join point was rejected, if they all were
+    pub(crate) fn error(&self) -> PortmanteauError {
+        if self.existing_word {
+            PortmanteauError::AllExistingWords
+        } else if self.unpronounceable {
+            PortmanteauError::Unpronounceable
+        } else if self.outside_limits {
+            PortmanteauError::OutsideLimits
+        } else {
+            PortmanteauError::AllRejected
+        }
+    }
+}
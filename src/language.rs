@@ -0,0 +1,574 @@
+use alloc::borrow::Cow;
+use core::iter;
+
+use crate::with_joined;
+
+type Letters = Cow<'static, [Cow<'static, str>]>;
+
+macro_rules! letters {
+    ($($letter:literal),* $(,)?) => {
+        Cow::Borrowed(&[$(Cow::Borrowed($letter)),*])
+    };
+}
+
+/// The vowel rules of a language, for use with
+/// [`PortmanteauBuilder::language`](crate::PortmanteauBuilder::language)
+///
+/// A vowel can be more than one letter (e.g. the Dutch "ij"). Semivowels
+/// (e.g. "y" in English) can optionally be treated as vowels too, except at
+/// the start of a word where they act as consonants ("yellow"). Matching
+/// vowels are tried in the order they're listed, with semivowels last
+///
+/// The consonant clusters a syllable can start with (onsets, e.g. "str") and
+/// end with (codas, e.g. "nds") are used to reject portmanteaus that can't be
+/// said. Where the words are joined, any run of consonants has to be in one
+/// of the input words, or be a coda followed by an onset (a run at the start
+/// or end of the portmanteau has to be a whole onset or coda respectively).
+/// Any run of more than two vowels across the join has to be in one of the
+/// input words too
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{LanguageProfile, Portmanteau};
+///
+/// let english = Portmanteau::default();
+/// assert_eq!(english.generate("crypt", "coffee"), None);
+///
+/// let english_with_y = Portmanteau::builder()
+///     .language(LanguageProfile::english().with_semivowels(true))
+///     .build();
+/// assert_eq!(
+///     english_with_y.generate("crypt", "coffee"),
+///     Some("croffee".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LanguageProfile {
+    vowels: Letters,
+    semivowels: Letters,
+    use_semivowels: bool,
+    onsets: Letters,
+    codas: Letters,
+    silent_endings: Letters,
+}
+
+impl LanguageProfile {
+    /// A language with the given vowels and semivowels. Semivowels are off
+    /// by default, see [`LanguageProfile::with_semivowels`]
+    ///
+    /// There are no consonant clusters, see [`LanguageProfile::with_clusters`],
+    /// or silent endings, see [`LanguageProfile::with_silent_endings`]
+    pub fn new<V, S>(vowels: V, semivowels: S) -> Self
+    where
+        V: IntoIterator,
+        V::Item: Into<Cow<'static, str>>,
+        S: IntoIterator,
+        S::Item: Into<Cow<'static, str>>,
+    {
+        LanguageProfile {
+            vowels: Cow::Owned(vowels.into_iter().map(Into::into).collect()),
+            semivowels: Cow::Owned(
+                semivowels.into_iter().map(Into::into).collect(),
+            ),
+            use_semivowels: false,
+            onsets: letters![],
+            codas: letters![],
+            silent_endings: letters![],
+        }
+    }
+
+    /// Vowels: a, e, i, o, u. Semivowels: y
+    ///
+    /// This is the default
+    pub const fn english() -> Self {
+        LanguageProfile {
+            vowels: letters!["a", "e", "i", "o", "u"],
+            semivowels: letters!["y"],
+            use_semivowels: false,
+            onsets: letters![
+                "bl", "br", "ch", "chr", "cl", "cr", "dr", "dw", "fl", "fr",
+                "gl", "gn", "gr", "kl", "kn", "kr", "ph", "phr", "pl", "pr",
+                "ps", "rh", "sc", "sch", "scr", "sh", "shr", "sk", "sl", "sm",
+                "sn", "sp", "sph", "spl", "spr", "st", "str", "sw", "th",
+                "thr", "tr", "tw", "wh", "wr",
+            ],
+            codas: letters![
+                "bb", "ch", "ck", "ct", "dd", "ff", "ft", "gg", "gh", "ght",
+                "ld", "lf", "lk", "ll", "lm", "lp", "ls", "lt", "mb", "mp",
+                "mph", "mps", "mpt", "nch", "nd", "ng", "ngs", "ngth", "nk",
+                "nks", "ns", "nt", "nth", "ph", "pt", "rb", "rch", "rd", "rf",
+                "rg", "rk", "rl", "rm", "rn", "rp", "rs", "rsh", "rst", "rt",
+                "rth", "sh", "sk", "sp", "ss", "st", "tch", "th", "ts", "tt",
+                "tz", "wl", "wn", "ws", "xt", "zz",
+            ],
+            silent_endings: letters!["e"],
+        }
+    }
+
+    /// Vowels: a, e, i, o, u, and their accented forms (á, é, í, ó, ú, ü).
+    /// Semivowels: y
+    pub const fn spanish() -> Self {
+        LanguageProfile {
+            vowels: letters![
+                "a", "e", "i", "o", "u", "á", "é", "í", "ó", "ú", "ü"
+            ],
+            semivowels: letters!["y"],
+            use_semivowels: false,
+            onsets: letters![
+                "bl", "br", "ch", "cl", "cr", "dr", "fl", "fr", "gl", "gr",
+                "ll", "pl", "pr", "rr", "tr",
+            ],
+            codas: letters!["bs", "ns", "rs"],
+            silent_endings: letters![],
+        }
+    }
+
+    /// Vowels: a, e, i, o, u, and umlauts (ä, ö, ü). Semivowels: y
+    pub const fn german() -> Self {
+        LanguageProfile {
+            vowels: letters!["a", "e", "i", "o", "u", "ä", "ö", "ü"],
+            semivowels: letters!["y"],
+            use_semivowels: false,
+            onsets: letters![
+                "bl", "br", "ch", "chr", "dr", "fl", "fr", "gl", "gn", "gr",
+                "kl", "kn", "kr", "pf", "pfl", "pfr", "pl", "pr", "sch",
+                "schl", "schm", "schn", "schr", "schw", "sp", "spr", "st",
+                "str", "tr", "zw",
+            ],
+            codas: letters![
+                "ch", "chs", "cht", "ck", "ckt", "ff", "ft", "gt", "hl", "hm",
+                "hn", "hr", "ld", "ll", "lt", "lz", "mm", "mpf", "mt", "nd",
+                "ng", "nk", "nn", "nt", "nz", "pf", "rb", "rd", "rg", "rk",
+                "rm", "rn", "rt", "rz", "sch", "scht", "ss", "st", "tsch",
+                "tt", "tz",
+            ],
+            silent_endings: letters![],
+        }
+    }
+
+    /// Vowels: a, e, i, o, u, and their accented forms (à, â, é, è, ê, ë, î,
+    /// ï, ô, ù, û, ü), æ, and œ. Semivowels: y, ÿ
+    pub const fn french() -> Self {
+        LanguageProfile {
+            vowels: letters![
+                "a", "e", "i", "o", "u", "à", "â", "é", "è", "ê", "ë", "î",
+                "ï", "ô", "ù", "û", "ü", "æ", "œ",
+            ],
+            semivowels: letters!["y", "ÿ"],
+            use_semivowels: false,
+            onsets: letters![
+                "bl", "br", "ch", "cl", "cr", "dr", "fl", "fr", "gl", "gn",
+                "gr", "ph", "pl", "pr", "ps", "sc", "sp", "st", "tr", "vr",
+            ],
+            codas: letters!["ct", "ls", "nt", "ps", "rd", "rs", "rt", "st"],
+            silent_endings: letters!["e", "es", "ent"],
+        }
+    }
+
+    /// Vowels: the digraph ij, a, e, i, o, u, and their accented forms (é, ë,
+    /// ï, ö, ü). Semivowels: y
+    pub const fn dutch() -> Self {
+        LanguageProfile {
+            vowels: letters![
+                "ij", "a", "e", "i", "o", "u", "é", "ë", "ï", "ö", "ü"
+            ],
+            semivowels: letters!["y"],
+            use_semivowels: false,
+            onsets: letters![
+                "bl", "br", "ch", "chr", "dr", "fl", "fr", "gl", "gr", "kl",
+                "kn", "kr", "pl", "pr", "sch", "schr", "sl", "sm", "sn", "sp",
+                "spr", "st", "str", "tr", "vl", "vr", "wr", "zw",
+            ],
+            codas: letters![
+                "cht", "ft", "gt", "kt", "lf", "lk", "lm", "lp", "ls", "lt",
+                "mp", "nd", "ng", "nk", "ns", "nt", "pt", "rd", "rf", "rg",
+                "rk", "rm", "rn", "rp", "rs", "rt", "sch", "st",
+            ],
+            silent_endings: letters![],
+        }
+    }
+
+    /// Vowels: a, e, i, o, u, y, and æ, ø, å, ä, ö, as used in Danish,
+    /// Norwegian, and Swedish. No semivowels
+    pub const fn scandinavian() -> Self {
+        LanguageProfile {
+            vowels: letters![
+                "a", "e", "i", "o", "u", "y", "æ", "ø", "å", "ä", "ö"
+            ],
+            semivowels: letters![],
+            use_semivowels: false,
+            onsets: letters![
+                "bl", "br", "dr", "fl", "fr", "gl", "gr", "kl", "kn", "kr",
+                "pl", "pr", "sk", "skr", "sl", "sm", "sn", "sp", "spr", "st",
+                "str", "sv", "tr",
+            ],
+            codas: letters![
+                "ft", "kt", "ld", "lk", "lt", "mp", "nd", "ng", "nk", "nt",
+                "rd", "rk", "rn", "rt", "sk", "st",
+            ],
+            silent_endings: letters![],
+        }
+    }
+
+    /// Vowels: a, e, i, o, u, and their accented forms (à, è, é, ì, ò, ó,
+    /// ù). No semivowels
+    pub const fn italian() -> Self {
+        LanguageProfile {
+            vowels: letters![
+                "a", "e", "i", "o", "u", "à", "è", "é", "ì", "ò", "ó", "ù"
+            ],
+            semivowels: letters![],
+            use_semivowels: false,
+            onsets: letters![
+                "bl", "br", "ch", "cl", "cr", "dr", "fl", "fr", "gh", "gl",
+                "gn", "gr", "pl", "pr", "sb", "sc", "sch", "scr", "sd", "sf",
+                "sg", "sl", "sm", "sn", "sp", "spr", "st", "str", "sv", "tr",
+            ],
+            codas: letters![],
+            silent_endings: letters![],
+        }
+    }
+
+    /// Vowels: α, ε, η, ι, ο, υ, ω, and their accented forms (ά, έ, ή, ί, ό,
+    /// ύ, ώ, ϊ, ϋ, ΐ, ΰ). No semivowels
+    pub const fn greek() -> Self {
+        LanguageProfile {
+            vowels: letters![
+                "α", "ε", "η", "ι", "ο", "υ", "ω", "ά", "έ", "ή", "ί", "ό",
+                "ύ", "ώ", "ϊ", "ϋ", "ΐ", "ΰ",
+            ],
+            semivowels: letters![],
+            use_semivowels: false,
+            onsets: letters![
+                "βλ", "βρ", "γκ", "γλ", "γρ", "δρ", "θρ", "κλ", "κρ", "μπ",
+                "ντ", "πλ", "πρ", "σκ", "σπ", "στ", "τζ", "τρ", "τσ", "φλ",
+                "φρ", "χλ", "χρ",
+            ],
+            codas: letters![],
+            silent_endings: letters![],
+        }
+    }
+
+    /// Vowels: а, е, и, о, у, ё, ы, э, ю, я. Semivowels: й
+    pub const fn russian() -> Self {
+        LanguageProfile {
+            vowels: letters!["а", "е", "и", "о", "у", "ё", "ы", "э", "ю", "я"],
+            semivowels: letters!["й"],
+            use_semivowels: false,
+            onsets: letters![
+                "бл", "бр", "вз", "вл", "вр", "вс", "гл", "гр", "др", "жд",
+                "зв", "зд", "кл", "кр", "пл", "пр", "ск", "сл", "см", "сн",
+                "сп", "ст", "стр", "тр", "хр", "шк", "шт",
+            ],
+            codas: letters!["ль", "нк", "нт", "рк", "рт", "ст"],
+            silent_endings: letters![],
+        }
+    }
+
+    /// The vowels, in the order they're tried, replacing the existing ones.
+    /// The semivowels and consonant clusters are kept
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::LanguageProfile;
+    ///
+    /// let english = LanguageProfile::english().with_vowels(["a", "e"]);
+    /// assert!(english.is_vowel('a'));
+    /// assert!(!english.is_vowel('o'));
+    /// assert!(english.codas().any(|coda| coda == "ngth"));
+    /// ```
+    pub fn with_vowels<V>(mut self, vowels: V) -> Self
+    where
+        V: IntoIterator,
+        V::Item: Into<Cow<'static, str>>,
+    {
+        self.vowels = Cow::Owned(vowels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Vowels: а, е, и, і, о, у, є, ї, ю, я. Semivowels: й
+    pub const fn ukrainian() -> Self {
+        LanguageProfile {
+            vowels: letters!["а", "е", "и", "і", "о", "у", "є", "ї", "ю", "я"],
+            semivowels: letters!["й"],
+            use_semivowels: false,
+            onsets: letters![
+                "бл", "бр", "вл", "вр", "гл", "гр", "др", "зв", "кл", "кр",
+                "пл", "пр", "ск", "сл", "см", "сн", "сп", "ст", "стр", "тр",
+                "хр",
+            ],
+            codas: letters!["нк", "нт", "рк", "рт", "ст"],
+            silent_endings: letters![],
+        }
+    }
+
+    /// Vowels: a, e, i, o, u, and every accented form of them used in
+    /// Western and Central European languages (e.g. ã, å, ą, ø, ő).
+    /// Semivowels: y, ÿ
+    ///
+    /// There are no consonant clusters or silent endings, as these vary
+    /// between the languages
+    pub const fn latin_extended() -> Self {
+        LanguageProfile {
+            vowels: letters![
+                "a", "e", "i", "o", "u", "à", "á", "â", "ã", "ä", "å", "æ",
+                "è", "é", "ê", "ë", "ì", "í", "î", "ï", "ò", "ó", "ô", "õ",
+                "ö", "ø", "œ", "ù", "ú", "û", "ü", "ā", "ă", "ą", "ē", "ĕ",
+                "ė", "ę", "ě", "ī", "ĭ", "į", "ı", "ō", "ŏ", "ő", "ū", "ŭ",
+                "ů", "ű", "ų",
+            ],
+            semivowels: letters!["y", "ÿ"],
+            use_semivowels: false,
+            onsets: letters![],
+            codas: letters![],
+            silent_endings: letters![],
+        }
+    }
+
+    /// Whether the semivowels should be treated as vowels
+    pub fn with_semivowels(mut self, use_semivowels: bool) -> Self {
+        self.use_semivowels = use_semivowels;
+        self
+    }
+
+    /// The consonant clusters allowed at the start of a syllable (onsets) and
+    /// the end of a syllable (codas), replacing the existing ones
+    ///
+    /// Single consonants are always allowed, so only clusters of two or more
+    /// letters need to be given. See [`LanguageProfile`] for how they're used
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::{LanguageProfile, Portmanteau};
+    ///
+    /// let generator = |language| {
+    ///     Portmanteau::builder()
+    ///         .overlap_length(1)
+    ///         .check_pronounceability(true)
+    ///         .language(language)
+    ///         .build()
+    /// };
+    ///
+    /// // "mbr" can be split into "m" and the onset "br"
+    /// let english = generator(LanguageProfile::english());
+    /// assert_eq!(
+    ///     english.generate("abercrombie", "brown"),
+    ///     Some("abercrombrown".to_string())
+    /// );
+    ///
+    /// let no_br = generator(
+    ///     LanguageProfile::english().with_clusters(["bl"], ["ng"]),
+    /// );
+    /// assert_eq!(
+    ///     no_br.generate("abercrombie", "brown"),
+    ///     Some("abercrown".to_string())
+    /// );
+    /// ```
+    pub fn with_clusters<O, C>(mut self, onsets: O, codas: C) -> Self
+    where
+        O: IntoIterator,
+        O::Item: Into<Cow<'static, str>>,
+        C: IntoIterator,
+        C::Item: Into<Cow<'static, str>>,
+    {
+        self.onsets = Cow::Owned(onsets.into_iter().map(Into::into).collect());
+        self.codas = Cow::Owned(codas.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Endings that don't make a syllable of their own after a single
+    /// consonant, like the "e" in "cave", replacing the existing ones
+    ///
+    /// These are only used to split words into syllables, see
+    /// [`Syllables`](crate::Syllables)
+    pub fn with_silent_endings<E>(mut self, silent_endings: E) -> Self
+    where
+        E: IntoIterator,
+        E::Item: Into<Cow<'static, str>>,
+    {
+        self.silent_endings =
+            Cow::Owned(silent_endings.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// The vowels, in the order they're tried, not including semivowels
+    pub fn vowels(&self) -> impl Iterator<Item = &str> {
+        self.vowels.iter().map(|vowel| vowel.as_ref())
+    }
+
+    /// The semivowels, whether or not they're being used
+    pub fn semivowels(&self) -> impl Iterator<Item = &str> {
+        self.semivowels.iter().map(|semivowel| semivowel.as_ref())
+    }
+
+    /// Whether the semivowels are treated as vowels
+    pub fn uses_semivowels(&self) -> bool {
+        self.use_semivowels
+    }
+
+    /// The consonant clusters allowed at the start of a syllable
+    pub fn onsets(&self) -> impl Iterator<Item = &str> {
+        self.onsets.iter().map(|onset| onset.as_ref())
+    }
+
+    /// The consonant clusters allowed at the end of a syllable
+    pub fn codas(&self) -> impl Iterator<Item = &str> {
+        self.codas.iter().map(|coda| coda.as_ref())
+    }
+
+    /// Endings that don't make a syllable of their own
+    pub fn silent_endings(&self) -> impl Iterator<Item = &str> {
+        self.silent_endings.iter().map(|ending| ending.as_ref())
+    }
+
+    /// Whether the letter is a vowel by itself, regardless of case.
+    /// Semivowels count if they're in use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::LanguageProfile;
+    ///
+    /// let dutch = LanguageProfile::dutch();
+    /// assert!(dutch.is_vowel('E'));
+    /// assert!(!dutch.is_vowel('j'));
+    /// assert!(!dutch.is_vowel('y'));
+    /// assert!(dutch.with_semivowels(true).is_vowel('y'));
+    /// ```
+    pub fn is_vowel(&self, letter: char) -> bool {
+        if letter.is_ascii() {
+            let letter = letter.to_ascii_lowercase() as u8;
+            return self
+                .all_vowels()
+                .any(|(vowel, _)| vowel.as_bytes() == [letter]);
+        }
+        letter.to_lowercase().all(|letter| {
+            let mut buffer = [0; 4];
+            let letter = &*letter.encode_utf8(&mut buffer);
+            self.all_vowels().any(|(vowel, _)| vowel == letter)
+        })
+    }
+
+    /// Whether joining the two fragments (from lowercase words) only makes a
+    /// consonant cluster that can be said: one from either input word, or a
+    /// coda followed by an onset, and no new run of more than two vowels
+    pub(crate) fn is_pronounceable(
+        &self,
+        left_fragment: &str,
+        right_fragment: &str,
+        left_word: &str,
+        right_word: &str,
+    ) -> bool {
+        self.is_sayable_cluster(
+            left_fragment,
+            right_fragment,
+            left_word,
+            right_word,
+        ) && self.is_sayable_vowel_run(
+            left_fragment,
+            right_fragment,
+            left_word,
+            right_word,
+        )
+    }
+
+    /// Whether the vowels either side of the join, if any, make a run of at
+    /// most two vowels, or one from either input word
+    fn is_sayable_vowel_run(
+        &self,
+        left_fragment: &str,
+        right_fragment: &str,
+        left_word: &str,
+        right_word: &str,
+    ) -> bool {
+        let is_vowel = |letter: char| self.is_vowel(letter);
+        let end =
+            &left_fragment[left_fragment.trim_end_matches(is_vowel).len()..];
+        let after = right_fragment.trim_start_matches(is_vowel);
+        let start = &right_fragment[..right_fragment.len() - after.len()];
+        if end.is_empty()
+            || start.is_empty()
+            || end.chars().chain(start.chars()).nth(2).is_none()
+        {
+            return true;
+        }
+        with_joined(end, start, |run| {
+            left_word.contains(run) || right_word.contains(run)
+        })
+    }
+
+    /// See [`LanguageProfile::is_pronounceable`]
+    fn is_sayable_cluster(
+        &self,
+        left_fragment: &str,
+        right_fragment: &str,
+        left_word: &str,
+        right_word: &str,
+    ) -> bool {
+        let is_consonant = |letter: char| !self.is_vowel(letter);
+        let before = left_fragment.trim_end_matches(is_consonant);
+        let after = right_fragment.trim_start_matches(is_consonant);
+        let end = &left_fragment[before.len()..];
+        let start = &right_fragment[..right_fragment.len() - after.len()];
+        if end.is_empty() || start.is_empty() {
+            // Nothing new
+            return true;
+        }
+
+        with_joined(end, start, |cluster| {
+            if left_word.contains(cluster) || right_word.contains(cluster) {
+                return true;
+            }
+            match (before.is_empty(), after.is_empty()) {
+                // No vowels at all
+                (true, true) => false,
+                // The start of the portmanteau
+                (true, false) => self.is_onset(cluster),
+                // The end of the portmanteau
+                (false, true) => self.is_coda(cluster),
+                // Between two vowels, so it can be split over two syllables
+                (false, false) => cluster
+                    .char_indices()
+                    .map(|(index, _)| index)
+                    .chain(iter::once(cluster.len()))
+                    .any(|index| {
+                        self.is_coda(&cluster[..index])
+                            && self.is_onset(&cluster[index..])
+                    }),
+            }
+        })
+    }
+
+    pub(crate) fn is_onset(&self, cluster: &str) -> bool {
+        cluster.chars().nth(1).is_none()
+            || self.onsets().any(|onset| onset == cluster)
+    }
+
+    fn is_coda(&self, cluster: &str) -> bool {
+        cluster.chars().nth(1).is_none()
+            || self.codas().any(|coda| coda == cluster)
+    }
+
+    /// Every vowel, and semivowel if in use, with whether it's a semivowel
+    pub(crate) fn all_vowels(&self) -> impl Iterator<Item = (&str, bool)> {
+        let semivowels = if self.use_semivowels {
+            &self.semivowels[..]
+        } else {
+            &[]
+        };
+        self.vowels().map(|vowel| (vowel, false)).chain(
+            semivowels
+                .iter()
+                .map(|semivowel| (semivowel.as_ref(), true)),
+        )
+    }
+}
+
+impl Default for LanguageProfile {
+    fn default() -> Self {
+        LanguageProfile::english()
+    }
+}
@@ -0,0 +1,181 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt,
+    iter::{self, FromIterator},
+};
+#[cfg(feature = "std")]
+use std::{fs, io, path::Path};
+
+use crate::fold_case;
+
+/// A collection of words that already exist, so portmanteaus that are just
+/// another word can be skipped
+///
+/// Give a generator one with
+/// [`PortmanteauBuilder::lexicon`](crate::PortmanteauBuilder::lexicon), and
+/// choose what happens to these portmanteaus with
+/// [`PortmanteauBuilder::existing_words`](crate::PortmanteauBuilder::existing_words).
+/// [`WordList`] is a compact implementation that can be loaded from a file
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{Lexicon, Portmanteau};
+///
+/// /// Every word ending in "es" is a word
+/// #[derive(Debug)]
+/// struct Plurals;
+///
+/// impl Lexicon for Plurals {
+///     fn contains(&self, word: &str) -> bool {
+///         word.ends_with("es")
+///     }
+/// }
+///
+/// let crutches = Portmanteau::default().generate("crutch", "itches");
+/// assert_eq!(crutches.unwrap(), "crutches");
+///
+/// let generator = Portmanteau::builder().lexicon(Plurals).build();
+/// assert_eq!(generator.generate("crutch", "itches"), None);
+/// ```
+pub trait Lexicon: fmt::Debug + Send + Sync {
+    /// Whether the word is in the lexicon
+    ///
+    /// The word is always given in lowercase (see [`WordList`] for what that
+    /// means exactly)
+    fn contains(&self, word: &str) -> bool;
+
+    /// Every word in the lexicon, in lowercase
+    ///
+    /// This is only needed to find the words a portmanteau could have come
+    /// from (see [`decompose`](crate::decompose)). By default there are none,
+    /// for lexicons that can't list their words
+    fn words(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(iter::empty())
+    }
+}
+
+/// What a generator does with portmanteaus that are already in its
+/// [`Lexicon`]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum ExistingWords {
+    /// Skip them, as if they were part of an input word
+    #[default]
+    Reject,
+    /// Only use them if there's nothing else, by moving them after every other
+    /// candidate
+    Last,
+}
+
+/// A sorted list of words, stored one after the other in a single string
+///
+/// Words are made lowercase the same way the generator does (each letter is
+/// replaced by the first letter of its lowercase form), and blank lines and
+/// surrounding whitespace are ignored
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{Lexicon, WordList};
+///
+/// let words = WordList::new(["Crutches", "itches", "crutch", "itches"]);
+/// assert_eq!(words.len(), 3);
+/// assert!(words.contains("crutches"));
+/// assert!(!words.contains("crutchitches"));
+/// assert_eq!(
+///     words.iter().collect::<Vec<_>>(),
+///     ["crutch", "crutches", "itches"]
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct WordList {
+    /// Every word, in order
+    letters: String,
+    /// The byte index each word starts at in `letters`, followed by the end
+    /// of the last word
+    starts: Vec<usize>,
+}
+
+impl WordList {
+    /// Creates a list of the given words
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut words = words
+            .into_iter()
+            .filter_map(|word| {
+                let word = word.as_ref().trim();
+                (!word.is_empty()).then(|| fold_case(word).into_owned())
+            })
+            .collect::<Vec<_>>();
+        words.sort_unstable();
+        words.dedup();
+
+        let mut list = WordList {
+            letters: String::with_capacity(words.iter().map(String::len).sum()),
+            starts: Vec::with_capacity(words.len() + 1),
+        };
+        for word in words {
+            list.starts.push(list.letters.len());
+            list.letters.push_str(&word);
+        }
+        list.starts.push(list.letters.len());
+        list
+    }
+
+    /// Reads a list of words from a file, with one word per line
+    ///
+    /// This needs the `std` feature
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(WordList::new(fs::read_to_string(path)?.lines()))
+    }
+
+    /// The number of words in the list
+    pub fn len(&self) -> usize {
+        self.starts.len().saturating_sub(1)
+    }
+
+    /// Whether the list has no words
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Every word in the list, in order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &str> {
+        (0..self.len()).map(move |n| self.get(n))
+    }
+
+    fn get(&self, n: usize) -> &str {
+        &self.letters[self.starts[n]..self.starts[n + 1]]
+    }
+}
+
+impl Lexicon for WordList {
+    fn contains(&self, word: &str) -> bool {
+        // Binary search
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.get(middle).cmp(word) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Equal => return true,
+                Ordering::Greater => high = middle,
+            }
+        }
+        false
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for WordList {
+    fn from_iter<I: IntoIterator<Item = S>>(words: I) -> Self {
+        WordList::new(words)
+    }
+}
@@ -1,3 +1,4 @@
+#![no_std]
 #![deny(missing_docs)]
 #![forbid(unsafe_code)]
 
@@ -6,8 +7,13 @@
 //!
 //! It isn't always possible to produce a portmanteau from the input words
 //! (there are some quality checks in place), so the exposed `portmanteau`
-//! function returns an `Option<String>`. This is currently the only function
-//! the crate exposes
+//! function returns an `Option<String>`. If you want to know how the words
+//! were joined, [`blend`] gives the same portmanteau as a [`Blend`], and
+//! [`best_blend`] rates every possible portmanteau and gives the best one
+//!
+//! The `portmanteau` function uses the default settings. If you want to tune
+//! the generation (minimum word length, vowels, etc.), build a reusable
+//! [`Portmanteau`] generator with [`PortmanteauBuilder`]
 //!
 //! This library's initial implementation was largely inspired by the work of [jamcowl's portmanteau bot](https://github.com/jamcowl/PORTMANTEAU-BOT).
 //! The full implementation is not available in their repository and over time
@@ -19,37 +25,922 @@
 //! algorithm produce. There are no checks for bad language in place, and there
 //! are no plans to add any. It is not my (or any contributer's) job to
 //! determine what is or isn't offensive
+//!
+//! # `no_std`
+//!
+//! The `std` feature is on by default. Without it, this crate only needs
+//! `alloc`, but [`WordList::from_file`], [`Blend::write_io`], and the
+//! [`std::error::Error`] implementation for [`PortmanteauError`] aren't
+//! available. The functions that use the default settings (e.g.
+//! [`portmanteau`]) create a new generator each time they're called, so keep a
+//! [`Portmanteau`] around instead if blending lots of words. A [`Blend`]
+//! borrows from the input words, so can be written out with
+//! [`Blend::write_to`] without allocating
+//!
+//! Generators share their strategies, scorer, and lexicon using
+//! [`alloc::sync::Arc`], so the target needs atomic pointers (i.e.
+//! `cfg(target_has_atomic = "ptr")`)
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-use std::ops::Deref;
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    iter,
+    ops::{Bound, ControlFlow, Deref, RangeBounds},
+    str,
+};
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
 
-const MIN_WORD_SIZE: usize = 4;
-const MATCHING_VOWEL_SEARCH_MARGIN: usize = 1;
-const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
+mod blend;
+mod builder;
+mod decompose;
+mod error;
+mod language;
+mod lexicon;
+mod many;
+mod names;
+mod overlap;
+mod partner;
+mod phonetic;
+mod prepared;
+mod score;
+mod strategy;
+mod syllable;
+mod whole_words;
 
-/// Stores the vowel locations within a word (search direction set by
-/// `from_left`)
+pub use blend::{Blend, Casing, JoinMethod, WordOrder};
+pub use builder::PortmanteauBuilder;
+pub use error::PortmanteauError;
+pub use language::LanguageProfile;
+pub use lexicon::{ExistingWords, Lexicon, WordList};
+pub use names::NameToken;
+pub use overlap::Overlap;
+pub use partner::PartnerIndex;
+pub use phonetic::Phonemes;
+pub use prepared::PreparedWord;
+pub use score::{Scorer, WeightedScorer};
+pub use strategy::{
+    FallbackVowels, JoinPoints, MatchingVowels, SharedTrios, Strategy,
+};
+pub use syllable::Syllables;
+
+use error::Rejections;
+
+/// A reusable portmanteau generator
 ///
-///                   A     E        I        O        U
-/// "helloski" -> `[None, Some(1), Some(7), Some(4), None]`
-#[derive(Debug, Copy, Clone)]
-struct VowelMap([Option<usize>; 5]);
+/// Create one with [`Portmanteau::builder`] if you want to change any of the
+/// settings, or use [`Portmanteau::default`] to get the same behaviour as the
+/// [`portmanteau`] function
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::Portmanteau;
+///
+/// let generator = Portmanteau::builder().overlap_length(2).build();
+/// assert_eq!(generator.generate("motor", "hotel"), Some("motel".to_string()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Portmanteau {
+    min_word_size: usize,
+    vowel_search_margin: usize,
+    language: LanguageProfile,
+    vowels: Vec<char>,
+    overlap: Overlap,
+    strategies: Vec<Arc<dyn Strategy>>,
+    check_pronounceability: bool,
+    scorer: Arc<dyn Scorer>,
+    lexicon: Option<Arc<dyn Lexicon>>,
+    existing_words: ExistingWords,
+    names: Option<NameToken>,
+    either_order: bool,
+    min_kept_letters: usize,
+    min_kept_fraction: f64,
+    output_length: (Bound<usize>, Bound<usize>),
+    reject_substrings: bool,
+    embed_words: bool,
+    compound_fallback: bool,
+}
+
+impl Portmanteau {
+    /// Start configuring a new generator, see [`PortmanteauBuilder`]
+    pub fn builder() -> PortmanteauBuilder {
+        PortmanteauBuilder::new()
+    }
+
+    /// The minimum length either input word can be
+    pub fn min_word_size(&self) -> usize {
+        self.min_word_size
+    }
+
+    /// How many letters at the start of the left word and end of the right
+    /// word are ignored when searching for vowels to join on
+    pub fn vowel_search_margin(&self) -> usize {
+        self.vowel_search_margin
+    }
+
+    /// The vowel rules in use
+    pub fn language(&self) -> &LanguageProfile {
+        &self.language
+    }
+
+    /// The letters treated as vowels, in the order they're tried
+    ///
+    /// Vowels made of more than one letter (e.g. the Dutch "ij") are left
+    /// out, see [`Portmanteau::language`] for those
+    pub fn vowels(&self) -> &[char] {
+        &self.vowels
+    }
+
+    /// The fewest letters the words must share to be joined by overlap
+    pub fn overlap_length(&self) -> usize {
+        self.overlap.min_length()
+    }
+
+    /// How many letters the words must share to be joined by overlap
+    pub fn overlap(&self) -> Overlap {
+        self.overlap
+    }
+
+    /// The strategies used to find join points, in the order they're tried
+    pub fn strategies(&self) -> impl Iterator<Item = &dyn Strategy> {
+        self.strategies.iter().map(Deref::deref)
+    }
+
+    /// Whether portmanteaus with consonant clusters or runs of vowels that
+    /// can't be said are rejected
+    pub fn checks_pronounceability(&self) -> bool {
+        self.check_pronounceability
+    }
+
+    /// The scorer used to rate portmanteaus
+    pub fn scorer(&self) -> &dyn Scorer {
+        self.scorer.deref()
+    }
+
+    /// The fewest letters of each input word a portmanteau must keep
+    pub fn min_kept_letters(&self) -> usize {
+        self.min_kept_letters
+    }
+
+    /// The smallest fraction of each input word's letters a portmanteau must
+    /// keep
+    pub fn min_kept_fraction(&self) -> f64 {
+        self.min_kept_fraction
+    }
+
+    /// How many letters a portmanteau can have
+    pub fn output_length(&self) -> (Bound<usize>, Bound<usize>) {
+        self.output_length
+    }
+
+    /// Whether a portmanteau keeps enough of each input word, and is an
+    /// allowed length
+    fn within_limits(&self, blend: &Blend) -> bool {
+        let unlimited =
+            self.min_kept_letters == 0 && self.min_kept_fraction <= 0.0;
+        if unlimited
+            && self.output_length == (Bound::Unbounded, Bound::Unbounded)
+        {
+            return true;
+        }
+        let left_kept = blend.left_fragment().chars().count();
+        let right_kept = blend.right_fragment().chars().count();
+        if !self.output_length.contains(&(left_kept + right_kept)) {
+            return false;
+        }
+        if unlimited {
+            return true;
+        }
+
+        // Letters shared where the words are joined count towards both
+        let (before, after) = score::shared_at_join(blend);
+        [
+            (left_kept + after, blend.left_word()),
+            (right_kept + before, blend.right_word()),
+        ]
+        .iter()
+        .all(|(kept, word)| {
+            *kept >= self.min_kept_letters
+                && *kept as f64
+                    >= self.min_kept_fraction * word.chars().count() as f64
+        })
+    }
+
+    /// Whether portmanteaus that are part of an input word are rejected
+    pub fn rejects_substrings(&self) -> bool {
+        self.reject_substrings
+    }
+
+    /// Whether portmanteaus that keep one word whole are tried, see
+    /// [`PortmanteauBuilder::embed_words`]
+    pub fn embeds_words(&self) -> bool {
+        self.embed_words
+    }
+
+    /// Whether the words are joined whole if nothing else works, see
+    /// [`PortmanteauBuilder::compound_fallback`]
+    pub fn falls_back_to_compounds(&self) -> bool {
+        self.compound_fallback
+    }
+
+    /// Whether the words are tried both ways round, see
+    /// [`PortmanteauBuilder::either_order`]
+    pub fn tries_either_order(&self) -> bool {
+        self.either_order
+    }
+
+    /// Which word of each name is blended, if blending names
+    pub fn names(&self) -> Option<NameToken> {
+        self.names
+    }
+
+    /// The word to blend from an input, which is the input itself unless
+    /// blending names
+    fn word_of<'a>(&self, input: &'a str) -> &'a str {
+        self.names.map_or(input, |token| token.pick(input))
+    }
+
+    /// How to capitalise a portmanteau of these words, see [`Casing`]
+    fn casing(&self, words: &[&str]) -> Casing {
+        match Casing::of_words(words) {
+            // Names are proper nouns
+            Casing::Lower if self.names.is_some() => Casing::Title,
+            casing => casing,
+        }
+    }
+
+    /// The words portmanteaus are checked against, if any
+    pub fn lexicon(&self) -> Option<&dyn Lexicon> {
+        self.lexicon.as_deref()
+    }
+
+    /// What happens to portmanteaus that are already in the lexicon
+    pub fn existing_words(&self) -> ExistingWords {
+        self.existing_words
+    }
+
+    /// Whether a portmanteau is already a word in this generator's lexicon
+    ///
+    /// This is always false if the generator doesn't have a lexicon
+    pub fn is_existing_word(&self, blend: &Blend) -> bool {
+        self.lexicon.as_ref().is_some_and(|lexicon| {
+            with_joined(blend.left_fragment(), blend.right_fragment(), |word| {
+                lexicon.contains(&fold_case(word))
+            })
+        })
+    }
+
+    /// Whether a portmanteau should come after every portmanteau that isn't
+    /// an existing word
+    fn deferred(&self, blend: &Blend) -> bool {
+        self.existing_words == ExistingWords::Last
+            && self.is_existing_word(blend)
+    }
+
+    /// How good a portmanteau is according to this generator's scorer, higher
+    /// is better
+    ///
+    /// Scores from the same generator can be compared across different pairs
+    /// of words, e.g. to rank a list of portmanteaus
+    pub fn score(&self, blend: &Blend) -> f64 {
+        self.scorer.score(self, blend)
+    }
+
+    /// Creates a portmanteau of the two given words if possible, using this
+    /// generator's settings
+    ///
+    /// See [`portmanteau`] for more details
+    pub fn generate(
+        &self,
+        left_word: &str,
+        right_word: &str,
+    ) -> Option<String> {
+        self.blend(left_word, right_word).map(|blend| {
+            // Only different if the case changes the length, e.g. "ß" to "SS"
+            let length =
+                blend.left_fragment().len() + blend.right_fragment().len();
+            let mut portmanteau = String::with_capacity(length);
+            // Writing to a string never fails
+            let _ = blend.write_to(&mut portmanteau);
+            portmanteau
+        })
+    }
+
+    /// Creates a portmanteau of the two given words if possible, using this
+    /// generator's settings, and describes how it was made
+    ///
+    /// See [`blend`] for more details
+    pub fn blend<'a>(
+        &self,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> Option<Blend<'a>> {
+        if self.either_order {
+            self.blend_either_order(left_word, right_word)
+                .map(|(blend, _)| blend)
+        } else {
+            self.blend_in_order(left_word, right_word, None)
+        }
+    }
+
+    /// [`Portmanteau::blend`] with the left word on the left, even if trying
+    /// either order, noting why join points were rejected if asked
+    ///
+    /// This is the same as the first of [`Portmanteau::candidates`], but
+    /// stops as soon as it's found
+    fn blend_in_order<'a>(
+        &self,
+        left_word: &'a str,
+        right_word: &'a str,
+        rejections: Option<&mut Rejections>,
+    ) -> Option<Blend<'a>> {
+        let left_word = self.word_of(left_word);
+        let right_word = self.word_of(right_word);
+        if !(self.validate(left_word) && self.validate(right_word)) {
+            return None;
+        }
+
+        let (left, right) = (fold_case(left_word), fold_case(right_word));
+        let blend = self.first_blend(
+            &left,
+            &right,
+            |strategy, visit| {
+                strategy.visit_join_points(self, &left, &right, visit)
+            },
+            rejections,
+        )?;
+        Some(
+            Blend::new(
+                left_word,
+                unfold(&left, left_word, blend.left_index()),
+                right_word,
+                unfold(&right, right_word, blend.right_index()),
+                blend.method(),
+            )
+            .with_casing(self.casing(&[left_word, right_word])),
+        )
+    }
+
+    /// The first acceptable blend of two valid, lowercase words, but existing
+    /// words are only used if there's nothing else
+    ///
+    /// `join_points` gives each strategy's join points to `visit`, stopping
+    /// as soon as it breaks. Why join points were rejected is noted in
+    /// `rejections`, if given
+    fn first_blend<'a>(
+        &self,
+        left_word: &'a str,
+        right_word: &'a str,
+        mut join_points: impl FnMut(
+            &dyn Strategy,
+            &mut dyn FnMut((usize, usize)) -> ControlFlow<()>,
+        ) -> ControlFlow<()>,
+        mut rejections: Option<&mut Rejections>,
+    ) -> Option<Blend<'a>> {
+        let mut found = None;
+        let mut deferred = None;
+        let mut consider = |(left_index, right_index), method| match self
+            .make_if_acceptable(
+                left_word,
+                left_index,
+                right_word,
+                right_index,
+                method,
+                rejections.as_deref_mut(),
+            ) {
+            Some(blend) if self.deferred(&blend) => {
+                deferred.get_or_insert(blend);
+                ControlFlow::Continue(())
+            },
+            Some(blend) => {
+                found = Some(blend);
+                ControlFlow::Break(())
+            },
+            None => ControlFlow::Continue(()),
+        };
+        let searched = self.strategies.iter().try_for_each(|strategy| {
+            let method = strategy.method();
+            join_points(strategy.deref(), &mut |join_point| {
+                consider(join_point, method)
+            })
+        });
+        if searched.is_continue()
+            && (self.embed_words || self.compound_fallback)
+        {
+            let _ = self
+                .whole_word_join_points(left_word, right_word)
+                .into_iter()
+                .try_for_each(|(left_index, right_index, method)| {
+                    consider((left_index, right_index), method)
+                });
+        }
+        found.or(deferred)
+    }
+
+    /// Creates a portmanteau of the two given words if possible, trying them
+    /// both ways round, using this generator's settings
+    ///
+    /// See [`blend_either_order`] for more details
+    pub fn blend_either_order<'a>(
+        &self,
+        first_word: &'a str,
+        second_word: &'a str,
+    ) -> Option<(Blend<'a>, WordOrder)> {
+        self.better_order(
+            self.blend_in_order(first_word, second_word, None),
+            self.blend_in_order(second_word, first_word, None),
+        )
+    }
+
+    /// The better of the same words blended both ways round, preferring the
+    /// order they were given in
+    fn better_order<'a>(
+        &self,
+        given: Option<Blend<'a>>,
+        swapped: Option<Blend<'a>>,
+    ) -> Option<(Blend<'a>, WordOrder)> {
+        match (given, swapped) {
+            (Some(given), Some(swapped)) => {
+                // Existing words only win if they're all there is
+                let rank = |blend| (!self.deferred(blend), self.score(blend));
+                if rank(&swapped) > rank(&given) {
+                    Some((swapped, WordOrder::Swapped))
+                } else {
+                    Some((given, WordOrder::Given))
+                }
+            },
+            (given, swapped) => given
+                .map(|blend| (blend, WordOrder::Given))
+                .or_else(|| swapped.map(|blend| (blend, WordOrder::Swapped))),
+        }
+    }
+
+    /// Creates the highest scoring portmanteau of the two given words if
+    /// possible, using this generator's settings
+    ///
+    /// See [`best_blend`] for more details
+    pub fn best_blend<'a>(
+        &self,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> Option<Blend<'a>> {
+        if self.either_order {
+            self.better_order(
+                self.best_blend_in_order(left_word, right_word),
+                self.best_blend_in_order(right_word, left_word),
+            )
+            .map(|(blend, _)| blend)
+        } else {
+            self.best_blend_in_order(left_word, right_word)
+        }
+    }
+
+    fn best_blend_in_order<'a>(
+        &self,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> Option<Blend<'a>> {
+        let mut best: Option<(f64, Blend<'a>)> = None;
+        for candidate in self.candidates(left_word, right_word) {
+            // Existing words are only considered if there's nothing else, and
+            // come after everything else
+            if best.is_some() && self.deferred(&candidate) {
+                break;
+            }
+            let score = self.score(&candidate);
+            // Ties go to the earlier candidate
+            best.replace_if(|(old, _)| score > *old, (score, candidate));
+        }
+        best.map(|(_, blend)| blend)
+    }
+
+    /// Creates a portmanteau of the two given words, or explains why it
+    /// couldn't, using this generator's settings
+    ///
+    /// See [`try_portmanteau`] for more details
+    pub fn try_blend<'a>(
+        &self,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> Result<Blend<'a>, PortmanteauError> {
+        let left_word = self.word_of(left_word);
+        let right_word = self.word_of(right_word);
+        self.check(left_word)?;
+        self.check(right_word)?;
+
+        let mut rejections = Rejections::default();
+        let blend = if self.either_order {
+            let given = self.blend_in_order(
+                left_word,
+                right_word,
+                Some(&mut rejections),
+            );
+            let swapped = self.blend_in_order(
+                right_word,
+                left_word,
+                Some(&mut rejections),
+            );
+            self.better_order(given, swapped).map(|(blend, _)| blend)
+        } else {
+            self.blend_in_order(left_word, right_word, Some(&mut rejections))
+        };
+        if let Some(blend) = blend {
+            return Ok(blend);
+        }
+
+        // Work out why nothing was produced
+        if !rejections.join_points {
+            let no_vowels = if self
+                .vowel_map_rtl(&fold_case(left_word))
+                .iter()
+                .all(Option::is_none)
+            {
+                Some(left_word)
+            } else if self
+                .vowel_map_ltr(&fold_case(right_word))
+                .iter()
+                .all(Option::is_none)
+            {
+                Some(right_word)
+            } else {
+                None
+            };
+            Err(match no_vowels {
+                Some(word) => PortmanteauError::NoVowels {
+                    word: word.to_string(),
+                },
+                None => PortmanteauError::NoJoinPoints,
+            })
+        } else {
+            Err(rejections.error())
+        }
+    }
+
+    /// Lazily creates every acceptable portmanteau of the two given words,
+    /// best first
+    ///
+    /// The first candidate is what [`Portmanteau::generate`] would return.
+    /// Duplicates (from different join points producing the same word) are
+    /// skipped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::Portmanteau;
+    ///
+    /// let generator = Portmanteau::default();
+    /// let mut candidates = generator.candidates("acceptable", "captain");
+    /// assert_eq!(candidates.next().unwrap(), "acceptain");
+    /// assert_eq!(candidates.next().unwrap(), "acceptaptain");
+    /// ```
+    pub fn candidates<'s, 'a: 's>(
+        &'s self,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> impl Iterator<Item = Blend<'a>> + 's {
+        let left_word = self.word_of(left_word);
+        let right_word = self.word_of(right_word);
+
+        // Step 1: validate input strings to be acceptable
+        let valid = self.validate(left_word) && self.validate(right_word);
+        let casing = self.casing(&[left_word, right_word]);
 
-impl VowelMap {
-    fn from_ltr(word: &str) -> Self {
-        let substring = &word[..word.len() - MATCHING_VOWEL_SEARCH_MARGIN];
-        VowelMap(VOWELS.map(|vowel| substring.find(vowel)))
+        // Words with capitals are blended in lowercase, then the join points
+        // are moved back onto the original words. As the lowercase words are
+        // owned here, these blends have to be made up front
+        let (lazy, eager) = match (fold_case(left_word), fold_case(right_word))
+        {
+            (Cow::Borrowed(_), Cow::Borrowed(_)) => {
+                (valid.then(|| self.blends_of(left_word, right_word)), None)
+            },
+            (left_folded, right_folded) => {
+                let eager = valid.then(|| {
+                    self.blends_of(&left_folded, &right_folded)
+                        .map(|blend| {
+                            Blend::new(
+                                left_word,
+                                unfold_index(
+                                    &left_folded,
+                                    left_word,
+                                    blend.left_index(),
+                                ),
+                                right_word,
+                                unfold_index(
+                                    &right_folded,
+                                    right_word,
+                                    blend.right_index(),
+                                ),
+                                blend.method(),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                });
+                (None, eager)
+            },
+        };
+
+        // Usually only the first candidate is wanted, so it's kept apart from
+        // the rest to save allocating for it
+        let mut first = None;
+        let mut seen = Vec::new();
+        let candidates = lazy
+            .into_iter()
+            .flatten()
+            .chain(eager.into_iter().flatten())
+            .map(move |blend| blend.with_casing(casing))
+            .filter(move |candidate: &Blend| {
+                if first
+                    .iter()
+                    .chain(&seen)
+                    .any(|old: &Blend| old.same_word(candidate))
+                {
+                    false
+                } else {
+                    match first {
+                        None => first = Some(*candidate),
+                        Some(_) => seen.push(*candidate),
+                    }
+                    true
+                }
+            });
+
+        // Existing words are moved to the end, so every candidate has to be
+        // made to find them
+        let (in_order, reordered) = match self.existing_words {
+            ExistingWords::Last if self.lexicon.is_some() => {
+                let (existing, others): (Vec<_>, Vec<_>) =
+                    candidates.partition(|blend| self.is_existing_word(blend));
+                (None, Some(others.into_iter().chain(existing)))
+            },
+            _ => (Some(candidates), None),
+        };
+        in_order
+            .into_iter()
+            .flatten()
+            .chain(reordered.into_iter().flatten())
+    }
+
+    /// Every acceptable blend (including duplicates) of two valid, lowercase
+    /// words, in order of preference
+    fn blends_of<'s, 'a: 's>(
+        &'s self,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> impl Iterator<Item = Blend<'a>> + 's {
+        // Step 2: Try each strategy in turn. Later strategies are only worked
+        // out if all the join points from the earlier ones are rejected
+        self.strategies
+            .iter()
+            .flat_map(move |strategy| {
+                let method = strategy.method();
+                strategy.join_points(self, left_word, right_word).map(
+                    move |(left_index, right_index)| {
+                        (left_index, right_index, method)
+                    },
+                )
+            })
+            .chain(
+                iter::once_with(move || {
+                    self.whole_word_join_points(left_word, right_word)
+                })
+                .flatten(),
+            )
+            .filter_map(move |(left_index, right_index, method)| {
+                self.make_if_acceptable(
+                    left_word,
+                    left_index,
+                    right_word,
+                    right_index,
+                    method,
+                    None,
+                )
+            })
     }
 
-    fn from_rtl(word: &str) -> Self {
-        let substring = &word[MATCHING_VOWEL_SEARCH_MARGIN..];
-        VowelMap(VOWELS.map(|vowel| {
-            substring.rfind(vowel)
-            // Make index relative to the whole word
-            .map(|index| index + MATCHING_VOWEL_SEARCH_MARGIN)
-        }))
+    #[inline]
+    fn validate(&self, s: &str) -> bool {
+        self.check(s).is_ok()
     }
+
+    fn check(&self, word: &str) -> Result<(), PortmanteauError> {
+        // Most words are only ASCII letters, which is quick to check
+        if word.len() >= self.min_word_size
+            && word.bytes().all(|byte| byte.is_ascii_alphabetic())
+        {
+            return Ok(());
+        }
+        if word.chars().count() < self.min_word_size {
+            return Err(PortmanteauError::TooShort {
+                word: word.to_string(),
+                min_word_size: self.min_word_size,
+            });
+        }
+        // Names can have hyphens and apostrophes between their letters, e.g.
+        // "Mary-Kate" or "O'Brien"
+        let joins_name = |index: usize, c: char| {
+            self.names.is_some()
+                && matches!(c, '-' | '\'')
+                && index > 0
+                && index + c.len_utf8() < word.len()
+        };
+        match word
+            .char_indices()
+            .find(|&(index, c)| !c.is_alphabetic() && !joins_name(index, c))
+            .map(|(_, c)| c)
+        {
+            Some(character) => Err(PortmanteauError::InvalidCharacter {
+                word: word.to_string(),
+                character,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn vowel_map_ltr(&self, word: &str) -> VowelMap {
+        VowelMap(self.vowels_ltr(word).collect())
+    }
+
+    pub(crate) fn vowel_map_rtl(&self, word: &str) -> VowelMap {
+        VowelMap(self.vowels_rtl(word).collect())
+    }
+
+    /// Where each vowel first appears in the word, as in a [`VowelMap`], only
+    /// searching for each vowel when it's needed
+    pub(crate) fn vowels_ltr<'s>(
+        &'s self,
+        word: &'s str,
+    ) -> impl Iterator<Item = Option<usize>> + 's {
+        let end = match self.vowel_search_margin {
+            0 => word.len(),
+            margin => word
+                .char_indices()
+                .rev()
+                .nth(margin - 1)
+                .map_or(0, |(index, _)| index),
+        };
+        self.language.all_vowels().map(move |(vowel, semivowel)| {
+            let start = if semivowel { first_char_len(word) } else { 0 };
+            word.get(start..end)
+                .and_then(|substring| find_vowel(substring, vowel))
+                // Make index relative to the whole word
+                .map(|index| index + start)
+        })
+    }
+
+    /// Where each vowel last appears in the word, see [`Self::vowels_ltr`]
+    pub(crate) fn vowels_rtl<'s>(
+        &'s self,
+        word: &'s str,
+    ) -> impl Iterator<Item = Option<usize>> + 's {
+        let margin_end = word
+            .char_indices()
+            .nth(self.vowel_search_margin)
+            .map_or(word.len(), |(index, _)| index);
+        self.language.all_vowels().map(move |(vowel, semivowel)| {
+            let start = if semivowel {
+                margin_end.max(first_char_len(word))
+            } else {
+                margin_end
+            };
+            rfind_vowel(&word[start..], vowel)
+                // Make index relative to the whole word
+                .map(|index| index + start)
+        })
+    }
+
+    /// Check if the portmanteau made with these two indices would be a
+    /// substring of an input word (if rejected), unpronounceable (if checked,
+    /// and not a compound), outside the generator's limits, an existing word
+    /// (if rejected), or the indices are nonsense, and if not, make it!
+    ///
+    /// If `rejections` is given, every check is made, to note which ones
+    /// failed
+    fn make_if_acceptable<'a>(
+        &self,
+        left_word: &'a str,
+        left_index: usize,
+        right_word: &'a str,
+        right_index: usize,
+        method: JoinMethod,
+        rejections: Option<&mut Rejections>,
+    ) -> Option<Blend<'a>> {
+        let first_fragment = left_word.get(..left_index)?;
+        let second_fragment = right_word.get(right_index..)?;
+        // Only where the first fragment first appears is checked, which is
+        // the start of the left word
+        let in_left_word =
+            || left_word[left_index..].starts_with(second_fragment);
+        let in_right_word = || {
+            first_fragment.len() + second_fragment.len() <= right_word.len()
+                && right_word.find(first_fragment).is_some_and(
+                    |first_frag_index| {
+                        right_word[first_frag_index + first_fragment.len()..]
+                            .starts_with(second_fragment)
+                    },
+                )
+        };
+        let is_substring =
+            || self.reject_substrings && (in_left_word() || in_right_word());
+        // Both words of a compound are said in full
+        let is_unpronounceable = || {
+            self.check_pronounceability
+                && method != JoinMethod::Compound
+                && !self.language.is_pronounceable(
+                    first_fragment,
+                    second_fragment,
+                    left_word,
+                    right_word,
+                )
+        };
+        let blend = || {
+            Blend::new(left_word, left_index, right_word, right_index, method)
+        };
+        let is_outside_limits = |blend| !self.within_limits(&blend);
+        let is_existing_word = |blend| {
+            self.existing_words == ExistingWords::Reject
+                && self.is_existing_word(&blend)
+        };
+
+        if let Some(rejections) = rejections {
+            return rejections
+                .note(
+                    is_substring(),
+                    is_unpronounceable(),
+                    is_outside_limits(blend()),
+                    is_existing_word(blend()),
+                )
+                .then(blend);
+        }
+        if is_substring() || is_unpronounceable() {
+            return None;
+        }
+        let blend = blend();
+        if is_outside_limits(blend) || is_existing_word(blend) {
+            return None;
+        }
+        Some(blend)
+    }
+
+    #[cfg(test)]
+    fn by_trios(&self, a: &str, b: &str) -> Option<String> {
+        SharedTrios
+            .join_points(self, a, b)
+            .find_map(|(a_index, b_index)| {
+                self.make_if_acceptable(
+                    a,
+                    a_index,
+                    b,
+                    b_index,
+                    JoinMethod::SharedTrio,
+                    None,
+                )
+            })
+            .as_ref()
+            .map(ToString::to_string)
+    }
+}
+
+impl Default for Portmanteau {
+    fn default() -> Self {
+        PortmanteauBuilder::new().build()
+    }
+}
+
+/// The generator used by the free functions, which is only made once as
+/// building one allocates
+#[cfg(feature = "std")]
+fn default_generator() -> &'static Portmanteau {
+    static DEFAULT: OnceLock<Portmanteau> = OnceLock::new();
+    DEFAULT.get_or_init(Portmanteau::default)
 }
 
+/// The generator used by the free functions, which is made each time as
+/// there's nowhere to keep one without `std`
+#[cfg(not(feature = "std"))]
+fn default_generator() -> Portmanteau {
+    Portmanteau::default()
+}
+
+/// Stores the vowel locations within a word (search direction set by which
+/// constructor was used), one entry per vowel (then semivowel) of the
+/// generator's language
+///
+/// ```text
+///                   A     E        I        O        U
+/// "helloski" -> `[None, Some(1), Some(7), Some(4), None]`
+/// ```
+#[derive(Debug, Clone)]
+struct VowelMap(Vec<Option<usize>>);
+
 impl Deref for VowelMap {
     type Target = [Option<usize>];
 
@@ -58,84 +949,149 @@ impl Deref for VowelMap {
     }
 }
 
-fn trios_of(
-    string: &str,
-) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
-    // Shouldn't happen in real world so leaving as debug assertion
-    // The for loop will panic if it happens
-    debug_assert!(
-        string.len() >= 3,
-        "Trios shouldn't be asked for from words with less than 3 letters"
-    );
+/// The word in lowercase, only allocating if it has capitals
+///
+/// Each character is replaced by exactly one character, so that indices can be
+/// moved between the two with [`unfold_index`]
+fn fold_case(word: &str) -> Cow<'_, str> {
+    if has_capitals(word) {
+        Cow::Owned(
+            word.chars()
+                .map(|c| c.to_lowercase().next().unwrap_or(c))
+                .collect(),
+        )
+    } else {
+        Cow::Borrowed(word)
+    }
+}
 
-    (0..string.len() - 2).map(move |index| &string[index..index + 3])
+/// Whether the string has any capital letters, only decoding characters from
+/// the first one that isn't ASCII
+pub(crate) fn has_capitals(string: &str) -> bool {
+    match string
+        .bytes()
+        .position(|byte| byte.is_ascii_uppercase() || !byte.is_ascii())
+    {
+        Some(index) if string.as_bytes()[index].is_ascii() => true,
+        Some(index) => string[index..].chars().any(char::is_uppercase),
+        None => false,
+    }
 }
 
-fn portmanteau_by_trios(a: &str, b: &str) -> Option<String> {
-    debug_assert!(
-        a.len() >= MIN_WORD_SIZE && b.len() >= MIN_WORD_SIZE,
-        "Less than {} letter words should have already been eliminated",
-        MIN_WORD_SIZE
-    );
+/// Turns a byte index into the output of [`fold_case`] into the same position
+/// in the original word, without any work if [`fold_case`] borrowed it
+fn unfold(folded: &str, original: &str, index: usize) -> usize {
+    if folded.as_ptr() == original.as_ptr() {
+        index
+    } else {
+        unfold_index(folded, original, index)
+    }
+}
 
-    let a_trios = trios_of(a).skip(1);
-
-    // Find indexes of matching trios
-    // Could optimise by looking at number of shared letters and skipping more
-    // entries in the trio if no letters are shared
-    a_trios
-        .enumerate()
-        // .rev here and in b_trios prioritises finding longer portmaneau by
-        // searching from the end of `a` and the start of `b`
-        .rev()
-        // Cartesian product with b_trios
-        .flat_map(|a_trio_tup| {
-            let b_trios = trios_of(b).enumerate().rev().skip(2).rev();
-            b_trios.map(move |b_trio_tup| (a_trio_tup, b_trio_tup))
-        })
-        .filter(|((_, a_trio), (_, b_trio))| a_trio == b_trio)
-        .find_map(|((a_pos, _), (b_pos, _))| {
-            make_if_acceptable(a, a_pos + 1, b, b_pos)
-        })
+/// Turns a byte index into the output of [`fold_case`] into the same position
+/// in the original word
+fn unfold_index(folded: &str, original: &str, index: usize) -> usize {
+    let n = folded[..index].chars().count();
+    original
+        .char_indices()
+        .nth(n)
+        .map_or(original.len(), |(index, _)| index)
+}
+
+/// Calls `f` with the two strings one after the other, only allocating if
+/// they're long
+fn with_joined<R>(left: &str, right: &str, f: impl FnOnce(&str) -> R) -> R {
+    let mut buffer = [0; 64];
+    let length = left.len() + right.len();
+    if length > buffer.len() {
+        return f(&[left, right].concat());
+    }
+    buffer[..left.len()].copy_from_slice(left.as_bytes());
+    buffer[left.len()..length].copy_from_slice(right.as_bytes());
+    // Two strings one after the other are always valid UTF-8
+    f(str::from_utf8(&buffer[..length]).unwrap_or_default())
 }
 
+/// The length in bytes of the first character of `string`
 #[inline]
-fn validate(s: &str) -> bool {
-    s.len() >= MIN_WORD_SIZE && s.chars().all(|c| c.is_ascii_lowercase())
+fn first_char_len(string: &str) -> usize {
+    string.chars().next().map_or(0, char::len_utf8)
 }
 
-/// Check if the portmanteau made with these two indices would be a
-/// substring of an input word, and if not, allocate it!
-fn make_if_acceptable(
-    left_word: &str,
-    left_index: usize,
-    right_word: &str,
-    right_index: usize,
-) -> Option<String> {
-    let first_fragment = &left_word[..left_index];
-    let second_fragment = &right_word[right_index..];
-    for input_word in [left_word, right_word] {
-        let Some(first_frag_index) = input_word.find(first_fragment) else {
-            continue;
-        };
-        if input_word[first_frag_index + first_fragment.len()..]
-            .starts_with(second_fragment)
-        {
-            // Portmanteau would be substring of an input word
-            return None;
-        }
+/// The first byte index of `vowel` in `word`, searching for a single byte or
+/// character rather than a string where possible, as that's much quicker
+#[inline]
+fn find_vowel(word: &str, vowel: &str) -> Option<usize> {
+    match vowel.as_bytes() {
+        [byte] => word.bytes().position(|letter| letter == *byte),
+        _ => match single_char(vowel) {
+            Some(vowel) => word.find(vowel),
+            None => word.find(vowel),
+        },
+    }
+}
+
+/// The last byte index of `vowel` in `word`, see [`find_vowel`]
+#[inline]
+fn rfind_vowel(word: &str, vowel: &str) -> Option<usize> {
+    match vowel.as_bytes() {
+        [byte] => word.bytes().rposition(|letter| letter == *byte),
+        _ => match single_char(vowel) {
+            Some(vowel) => word.rfind(vowel),
+            None => word.rfind(vowel),
+        },
     }
-    Some(format!(
-        "{}{}",
-        &left_word[..left_index],
-        &right_word[right_index..],
-    ))
+}
+
+/// The only character of `string`, if it's one character long
+#[inline]
+fn single_char(string: &str) -> Option<char> {
+    let mut chars = string.chars();
+    chars.next().filter(|_| chars.next().is_none())
+}
+
+/// Overlapping windows of `size` letters, e.g. trios when `size` is 3
+#[cfg(test)]
+fn ngrams_of(
+    string: &str,
+    size: usize,
+) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+    // Shouldn't happen in real world so leaving as debug assertion
+    // The range will panic if it happens
+    debug_assert!(
+        string.chars().count() >= size,
+        "{}-grams shouldn't be asked for from words with less than {} letters",
+        size,
+        size
+    );
+
+    let boundaries = letter_boundaries(string);
+    (0..boundaries.len() - size)
+        .map(move |n| &string[boundaries[n]..boundaries[n + size]])
+}
+
+/// The byte index each letter starts at, followed by the length of the string,
+/// so the `n`th letter is `string[boundaries[n]..boundaries[n + 1]]`
+fn letter_boundaries(string: &str) -> Vec<usize> {
+    string
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(iter::once(string.len()))
+        .collect()
 }
 
 /// This function creates a portmanteau of the two given words if possible
 ///
-/// Both inputs given should be lowercase single words, without punctuation, and
-/// 5 or more letters in length. Doing so would result in receiving `None`
+/// Both inputs given should be single words, without punctuation, and 4 or
+/// more letters in length. Not doing so would result in receiving `None`
+///
+/// Words are blended regardless of case, and the result is capitalised to
+/// match the inputs, see [`Casing`]
+///
+/// This uses the default settings, see [`Portmanteau`] if you need to change
+/// them
+///
+/// Without the `std` feature, see [the `no_std` notes](crate#no_std)
 ///
 /// # Examples
 ///
@@ -145,68 +1101,215 @@ fn make_if_acceptable(
 /// let something = portmanteau("fluffy", "turtle");
 /// assert_eq!(something, Some(String::from("flurtle")));
 ///
-/// let nothing = portmanteau("tiny", "word");
+/// let nothing = portmanteau("tin", "can");
 /// assert_eq!(nothing, None);
 /// ```
 pub fn portmanteau(left_word: &str, right_word: &str) -> Option<String> {
-    // Step 1: validate input strings to be acceptable
-    if !(validate(left_word) && validate(right_word)) {
-        return None;
-    }
+    default_generator().generate(left_word, right_word)
+}
 
-    // Step 2: Try and get a portmanteau by trios
-    portmanteau_by_trios(left_word, right_word).or_else(|| {
-        // Step 3: Try and join on vowels (ideally a matching pair)
-        let left_vowels = VowelMap::from_rtl(left_word);
-        let right_vowels = VowelMap::from_ltr(right_word);
+/// This function creates a portmanteau of the two given words if possible,
+/// and describes how it was made
+///
+/// This is the same as [`portmanteau`], but the [`Blend`] returned borrows
+/// from the input words and says where they were cut and why
+///
+/// Without the `std` feature, see [the `no_std` notes](crate#no_std)
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{blend, JoinMethod};
+///
+/// let chromise = blend("chrome", "promise").unwrap();
+/// assert_eq!(chromise.left_fragment(), "ch");
+/// assert_eq!(chromise.right_fragment(), "romise");
+/// assert_eq!(chromise.method(), JoinMethod::SharedTrio);
+///
+/// assert_eq!(blend("tin", "can"), None);
+/// ```
+pub fn blend<'a>(left_word: &'a str, right_word: &'a str) -> Option<Blend<'a>> {
+    default_generator().blend(left_word, right_word)
+}
 
-        let mut chosen_left_vowel_index: Option<usize> = None;
-        let mut chosen_right_vowel_index: Option<usize> = None;
-        for (left_vowel_index, right_vowel_index) in
-            left_vowels.iter().zip(right_vowels.deref())
-        {
-            match (left_vowel_index, right_vowel_index) {
-                (Some(left_vowel_index), Some(right_vowel_index)) => {
-                    // Matching vowels is best-case, immediately see if
-                    // it works
-                    let potential_answer = make_if_acceptable(
-                        left_word,
-                        *left_vowel_index,
-                        right_word,
-                        *right_vowel_index,
-                    );
-                    if potential_answer.is_some() {
-                        return potential_answer;
-                    }
-                    chosen_left_vowel_index = Some(*left_vowel_index);
-                    chosen_right_vowel_index = Some(*right_vowel_index);
-                },
-                (Some(left_index), None) => chosen_left_vowel_index
-                    .replace_if(|inner| left_index > inner, *left_index),
-                (None, Some(right_index)) => chosen_right_vowel_index
-                    .replace_if(|inner| right_index < inner, *right_index),
-                (None, None) => {},
-            }
-        }
-        // println!(
-        //     "\n{left_vowels:?} <- {left_word:?} -> \
-        //      {chosen_left_vowel_index:?}",
-        // );
-        // println!(
-        //     "{right_vowels:?} <- {right_word:?} -> \
-        //      {chosen_right_vowel_index:?}",
-        // );
-        chosen_left_vowel_index
-            .zip(chosen_right_vowel_index)
-            .and_then(|(left_vowel_index, right_vowel_index)| {
-                make_if_acceptable(
-                    left_word,
-                    left_vowel_index,
-                    right_word,
-                    right_vowel_index,
-                )
-            })
-    })
+/// This function creates a portmanteau of the two given words if possible,
+/// trying them both ways round
+///
+/// This is the same as [`blend`], but the words are also blended the other
+/// way round (the second word on the left), and whichever portmanteau scores
+/// higher (see [`best_blend`]) is given, along with which way round it is.
+/// Ties go to the order the words were given in
+///
+/// Without the `std` feature, see [the `no_std` notes](crate#no_std)
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{blend, blend_either_order, WordOrder};
+///
+/// assert_eq!(blend("itches", "crutch").unwrap(), "itchutch");
+/// let (blend, order) = blend_either_order("itches", "crutch").unwrap();
+/// assert_eq!(blend, "crutches");
+/// assert_eq!(order, WordOrder::Swapped);
+/// assert_eq!(blend.left_word(), "crutch");
+/// ```
+pub fn blend_either_order<'a>(
+    first_word: &'a str,
+    second_word: &'a str,
+) -> Option<(Blend<'a>, WordOrder)> {
+    default_generator().blend_either_order(first_word, second_word)
+}
+
+/// This function creates a portmanteau of the two given words, or explains
+/// why it couldn't
+///
+/// This is the same as [`blend`], but gives a [`PortmanteauError`] instead of
+/// `None`, which can be used to tell the user what to change
+///
+/// Without the `std` feature, see [the `no_std` notes](crate#no_std)
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{try_portmanteau, PortmanteauError};
+///
+/// assert_eq!(try_portmanteau("fluffy", "turtle").unwrap(), "flurtle");
+///
+/// assert_eq!(
+///     try_portmanteau("tin", "can"),
+///     Err(PortmanteauError::TooShort {
+///         word: String::from("tin"),
+///         min_word_size: 4,
+///     })
+/// );
+/// assert_eq!(
+///     try_portmanteau("swords", "words"),
+///     Err(PortmanteauError::AllRejected)
+/// );
+/// ```
+pub fn try_portmanteau<'a>(
+    left_word: &'a str,
+    right_word: &'a str,
+) -> Result<Blend<'a>, PortmanteauError> {
+    default_generator().try_blend(left_word, right_word)
+}
+
+/// This function creates a portmanteau of all the given words, in order, if
+/// possible
+///
+/// Neighbouring words are joined in the same ways as [`portmanteau`], but the
+/// join points are chosen together so that every word in the middle keeps
+/// some of its letters (rather than blending the first two, and then blending
+/// that with the third, which often loses the second word entirely). Fewer
+/// than two words will give `None`
+///
+/// Without the `std` feature, see [the `no_std` notes](crate#no_std)
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{portmanteau, portmanteau_many};
+///
+/// assert_eq!(
+///     portmanteau_many(&["basket", "butter", "cookie"]),
+///     Some("baskuttookie".to_string())
+/// );
+/// // Whereas blending one pair at a time loses "butter"
+/// let basker = portmanteau("basket", "butter").unwrap();
+/// assert_eq!(portmanteau(&basker, "cookie"), Some("baskookie".to_string()));
+///
+/// assert_eq!(
+///     portmanteau_many(&["liquid", "slinky"]),
+///     portmanteau("liquid", "slinky")
+/// );
+/// ```
+pub fn portmanteau_many(words: &[&str]) -> Option<String> {
+    default_generator().generate_many(words)
+}
+
+/// This function creates the highest scoring portmanteau of the two given
+/// words if possible
+///
+/// [`blend`] gives the first acceptable portmanteau found, whereas this scores
+/// every candidate (see [`Portmanteau::candidates`]) with [`WeightedScorer`]
+/// and picks the best. Use [`Portmanteau::score`] to get the score itself
+///
+/// Without the `std` feature, see [the `no_std` notes](crate#no_std)
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{best_blend, blend};
+///
+/// assert_eq!(blend("abercrombie", "complex").unwrap(), "abercrombiex");
+/// assert_eq!(best_blend("abercrombie", "complex").unwrap(), "abercromplex");
+///
+/// assert_eq!(best_blend("tin", "can"), None);
+/// ```
+pub fn best_blend<'a>(
+    left_word: &'a str,
+    right_word: &'a str,
+) -> Option<Blend<'a>> {
+    default_generator().best_blend(left_word, right_word)
+}
+
+/// This function creates every acceptable portmanteau of the two given words,
+/// best first
+///
+/// The first entry is what [`blend`] would return, so the `Vec` is empty in
+/// the same situations that [`portmanteau`] gives `None`. If you don't need
+/// all of them at once, [`Portmanteau::candidates`] creates them lazily
+///
+/// Without the `std` feature, see [the `no_std` notes](crate#no_std)
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::candidates;
+///
+/// let options = candidates("acceptable", "captain");
+/// assert_eq!(options, ["acceptain", "acceptaptain", "acceptablaptain"]);
+///
+/// assert!(candidates("tin", "can").is_empty());
+/// ```
+pub fn candidates<'a>(
+    left_word: &'a str,
+    right_word: &'a str,
+) -> Vec<Blend<'a>> {
+    default_generator()
+        .candidates(left_word, right_word)
+        .collect()
+}
+
+/// This function finds the pairs of words from a lexicon that [`portmanteau`]
+/// would blend into the given word, best first
+///
+/// This is the inverse of [`blend`]: for each pair found, blending the two
+/// words gives the same word (regardless of case). Pairs are ranked by how
+/// good a portmanteau the word is of them (see [`best_blend`]). Only the words
+/// listed by [`Lexicon::words`] are tried
+///
+/// Without the `std` feature, see [the `no_std` notes](crate#no_std)
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{decompose, WordList};
+///
+/// let lexicon = WordList::new(["liquid", "slinky", "squid", "pinky", "drink"]);
+/// let sources = decompose("Liquinky", &lexicon);
+/// assert_eq!(sources, ["liquinky", "liquinky"]);
+/// assert_eq!(sources[0].left_word(), "liquid");
+/// assert_eq!(sources[0].right_word(), "pinky");
+/// assert_eq!(sources[1].right_word(), "slinky");
+///
+/// assert!(decompose("banana", &lexicon).is_empty());
+/// ```
+pub fn decompose<'l, L: Lexicon + ?Sized>(
+    word: &str,
+    lexicon: &'l L,
+) -> Vec<Blend<'l>> {
+    default_generator().decompose(word, lexicon)
 }
 
 trait OptionExt<T> {
@@ -231,65 +1334,72 @@ mod unit_tests {
     fn trios() {
         let solutions: [&str; 3] = ["abc", "bcd", "cde"];
 
-        assert_eq!(trios_of("abc").collect::<Vec<_>>(), solutions[0..1]);
-        assert_eq!(*trios_of("abcd").collect::<Vec<_>>(), solutions[0..2]);
-        assert_eq!(*trios_of("abcde").collect::<Vec<_>>(), solutions[0..3]);
+        assert_eq!(ngrams_of("abc", 3).collect::<Vec<_>>(), solutions[0..1]);
+        assert_eq!(*ngrams_of("abcd", 3).collect::<Vec<_>>(), solutions[0..2]);
+        assert_eq!(*ngrams_of("abcde", 3).collect::<Vec<_>>(), solutions[0..3]);
     }
 
     #[test]
     #[should_panic]
     fn trios_panic_too_short() {
-        trios_of("").for_each(drop);
+        ngrams_of("", 3).for_each(drop);
     }
 
     #[test]
     fn by_trios() {
         assert_eq!(
-            portmanteau_by_trios("chrome", "promise"),
+            Portmanteau::default().by_trios("chrome", "promise"),
             Some("chromise".to_string())
         );
         assert_eq!(
-            portmanteau_by_trios("crime", "experimental"),
+            Portmanteau::default().by_trios("crime", "experimental"),
             Some("crimental".to_string())
         );
         assert_eq!(
-            portmanteau_by_trios("pleasurable", "breaststroke"),
+            Portmanteau::default().by_trios("pleasurable", "breaststroke"),
             Some("pleaststroke".to_string())
         );
         assert_eq!(
-            portmanteau_by_trios("unthreatening", "recreation"),
+            Portmanteau::default().by_trios("unthreatening", "recreation"),
             Some("unthreation".to_string())
         );
     }
 
     #[test]
     fn by_trios_no_vowels() {
+        let generator = Portmanteau::default();
         assert_eq!(
-            portmanteau_by_trios("sdfghjk", "qwrdfgvbnm"),
+            generator.by_trios("sdfghjk", "qwrdfgvbnm"),
             Some("sdfgvbnm".to_string())
         );
         assert_eq!(
-            portmanteau("sdfghjk", "qwrdfgvbnm"),
+            generator.generate("sdfghjk", "qwrdfgvbnm"),
             Some("sdfgvbnm".to_string()),
             "The portmanteau function is rejecting due to lack of vowels too \
              early!"
         );
+        // It can't be said though
+        let checked =
+            Portmanteau::builder().check_pronounceability(true).build();
+        assert_eq!(checked.generate("sdfghjk", "qwrdfgvbnm"), None);
     }
 
     #[test]
     #[should_panic]
     fn by_trios_panic_too_short() {
-        portmanteau_by_trios("tin", "can");
+        Portmanteau::default().by_trios("tin", "can");
     }
 
     #[test]
     fn validation() {
-        assert!(validate("hello"));
-        assert!(validate("smol"));
-        assert!(!validate("Hello"));
-        assert!(!validate("symbols!"));
-        assert!(!validate("s p a c e s"));
-        assert!(!validate("😃😂😉🤩🙄"));
-        assert!(!validate("accénts"))
+        assert!(Portmanteau::default().validate("hello"));
+        assert!(Portmanteau::default().validate("smol"));
+        assert!(Portmanteau::default().validate("Hello"));
+        assert!(!Portmanteau::default().validate("symbols!"));
+        assert!(!Portmanteau::default().validate("s p a c e s"));
+        assert!(!Portmanteau::default().validate("😃😂😉🤩🙄"));
+        assert!(Portmanteau::default().validate("accénts"));
+        assert!(Portmanteau::default().validate("αλφάβητο"));
+        assert!(Portmanteau::default().validate("Αλφάβητο"));
     }
 }
@@ -0,0 +1,147 @@
+use alloc::{boxed::Box, string::String, sync::Arc, vec, vec::Vec};
+use core::iter;
+
+use crate::{
+    Blend, JoinMethod, JoinPoints, Overlap, Portmanteau, SharedTrios, Strategy,
+};
+
+/// The fewest letters a word in the middle of a portmanteau of several words
+/// must keep, so it can still be recognised
+const MIN_MIDDLE_LETTERS: usize = 2;
+
+/// The lowest total rank of a chain of candidates ending at a candidate, and
+/// the index of the candidate before it, or `None` if no chain reaches it
+type Link = Option<(usize, Option<usize>)>;
+
+impl Portmanteau {
+    /// Creates a portmanteau of all the given words, in order, if possible
+    ///
+    /// See [`portmanteau_many`](crate::portmanteau_many) for more details
+    pub fn generate_many(&self, words: &[&str]) -> Option<String> {
+        if words.len() < 2 {
+            return None;
+        }
+        let words = words
+            .iter()
+            .map(|word| self.word_of(word))
+            .collect::<Vec<_>>();
+
+        // The usual candidates often don't leave enough of the middle words,
+        // so any shared letter, then any two vowels, are also considered, as
+        // a last resort
+        let relaxed = Portmanteau {
+            overlap: Overlap::Fixed(1),
+            strategies: vec![Arc::new(SharedTrios), Arc::new(AnyVowels)],
+            ..self.clone()
+        };
+
+        // Every way of joining each neighbouring pair of words, best first
+        let pairs = words
+            .windows(2)
+            .map(|pair| {
+                self.candidates(pair[0], pair[1])
+                    .chain(relaxed.candidates(pair[0], pair[1]))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // The best chain leading up to each candidate of each pair. Lower
+        // ranks are better candidates
+        let mut chains: Vec<Vec<Link>> = Vec::with_capacity(pairs.len());
+        chains
+            .push((0..pairs[0].len()).map(|rank| Some((rank, None))).collect());
+        for (n, candidates) in pairs.iter().enumerate().skip(1) {
+            let previous_candidates = &pairs[n - 1];
+            let previous_chains = &chains[n - 1];
+            let links = candidates
+                .iter()
+                .enumerate()
+                .map(|(rank, candidate)| {
+                    previous_candidates
+                        .iter()
+                        .zip(previous_chains)
+                        .enumerate()
+                        .filter_map(|(index, (previous, chain))| {
+                            chain
+                                .filter(|_| keeps_middle(previous, candidate))
+                                .map(|(total, _)| (total + rank, Some(index)))
+                        })
+                        .min_by_key(|(total, _)| *total)
+                })
+                .collect();
+            chains.push(links);
+        }
+
+        // Find the best complete chain, and follow it back to the start
+        let (mut index, _) = chains
+            .last()?
+            .iter()
+            .enumerate()
+            .filter_map(|(index, chain)| chain.map(|(total, _)| (index, total)))
+            .min_by_key(|(_, total)| *total)?;
+        let mut chosen = Vec::with_capacity(pairs.len());
+        for (candidates, chain) in pairs.iter().zip(&chains).rev() {
+            chosen.push(candidates[index]);
+            match chain[index].and_then(|(_, previous)| previous) {
+                Some(previous) => index = previous,
+                None => break,
+            }
+        }
+        chosen.reverse();
+
+        let mut fragments = Vec::with_capacity(words.len());
+        let mut start = 0;
+        for (word, blend) in words.iter().zip(&chosen) {
+            fragments.push(&word[start..blend.left_index()]);
+            start = blend.right_index();
+        }
+        fragments.push(&words[words.len() - 1][start..]);
+
+        Some(
+            self.casing(&words)
+                .apply(fragments.iter().flat_map(|fragment| fragment.chars()))
+                .collect(),
+        )
+    }
+}
+
+/// Whether the word shared by two neighbouring blends keeps enough letters
+/// between the two join points
+fn keeps_middle(left: &Blend, right: &Blend) -> bool {
+    right
+        .left_word()
+        .get(left.right_index()..right.left_index())
+        .is_some_and(|middle| middle.chars().count() >= MIN_MIDDLE_LETTERS)
+}
+
+/// Joins any vowel, or the end, of the left word to any vowel of the right
+/// word, keeping as much of the left word as possible
+#[derive(Debug)]
+struct AnyVowels;
+
+impl Strategy for AnyVowels {
+    fn method(&self) -> JoinMethod {
+        JoinMethod::FallbackVowel
+    }
+
+    fn join_points<'a>(
+        &'a self,
+        generator: &'a Portmanteau,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> JoinPoints<'a> {
+        let vowels = move |word: &'a str| {
+            word.char_indices()
+                .filter(move |&(_, letter)| generator.language.is_vowel(letter))
+                .map(|(index, _)| index)
+        };
+        Box::new(
+            iter::once(left_word.len())
+                .chain(vowels(left_word).rev())
+                .flat_map(move |left_index| {
+                    vowels(right_word)
+                        .map(move |right_index| (left_index, right_index))
+                }),
+        )
+    }
+}
@@ -0,0 +1,51 @@
+/// Which word of a name (or other phrase) is blended, when blending names
+///
+/// Names are split into words on whitespace, and only the chosen word of each
+/// name is blended. See
+/// [`PortmanteauBuilder::for_names`](crate::PortmanteauBuilder::for_names)
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{NameToken, Portmanteau};
+///
+/// let first_names = Portmanteau::builder().for_names().build();
+/// let blend = first_names.blend("Brad Pitt", "Angelina Jolie").unwrap();
+/// assert_eq!(blend, "Brangelina");
+/// assert_eq!(blend.left_word(), "Brad");
+///
+/// let surnames =
+///     Portmanteau::builder().for_names().names(NameToken::Last).build();
+/// let blend = surnames.blend("Brad Pitt", "Angelina Jolie").unwrap();
+/// assert_eq!(blend, "Pie");
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum NameToken {
+    /// The first word, e.g. a first name
+    #[default]
+    First,
+    /// The last word, e.g. a surname
+    Last,
+}
+
+impl NameToken {
+    /// The chosen word of a name, or an empty string if it has no words
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::NameToken;
+    ///
+    /// assert_eq!(NameToken::First.pick("Angelina Jolie"), "Angelina");
+    /// assert_eq!(NameToken::Last.pick(" Angelina  Jolie "), "Jolie");
+    /// assert_eq!(NameToken::Last.pick("Madonna"), "Madonna");
+    /// ```
+    pub fn pick(self, name: &str) -> &str {
+        let mut words = name.split_whitespace();
+        match self {
+            NameToken::First => words.next(),
+            NameToken::Last => words.next_back(),
+        }
+        .unwrap_or_default()
+    }
+}
@@ -0,0 +1,81 @@
+/// How many letters the words must share to be joined by
+/// [`SharedTrios`](crate::SharedTrios)
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{Overlap, Portmanteau};
+///
+/// let trios = Portmanteau::default();
+/// assert_eq!(trios.generate("motor", "hotel").unwrap(), "mototel");
+///
+/// // Short words only need to share two letters
+/// let adaptive = Portmanteau::builder().overlap(Overlap::Adaptive).build();
+/// assert_eq!(adaptive.generate("motor", "hotel").unwrap(), "motel");
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Overlap {
+    /// Always this many letters (3 by default, i.e. trios)
+    Fixed(usize),
+    /// Depends on the length of the shorter word: 2 letters for words of up to
+    /// 5 letters, 3 for up to 9 letters, and 4 for longer words
+    Adaptive,
+    /// At least this many letters, preferring the longest run of letters the
+    /// words share
+    Longest(usize),
+}
+
+impl Overlap {
+    /// The fewest letters that can be shared
+    pub fn min_length(&self) -> usize {
+        match *self {
+            Overlap::Fixed(length) | Overlap::Longest(length) => length,
+            Overlap::Adaptive => 2,
+        }
+    }
+
+    /// The numbers of letters to look for the two words sharing, in order of
+    /// preference
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::Overlap;
+    ///
+    /// let lengths = |overlap: Overlap| {
+    ///     overlap.lengths("liquid", "slinky").collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(lengths(Overlap::Fixed(3)), [3]);
+    /// assert_eq!(lengths(Overlap::Adaptive), [3]);
+    /// assert_eq!(lengths(Overlap::Longest(3)), [6, 5, 4, 3]);
+    /// ```
+    pub fn lengths(
+        &self,
+        left_word: &str,
+        right_word: &str,
+    ) -> impl Iterator<Item = usize> {
+        let shorter =
+            || left_word.chars().count().min(right_word.chars().count());
+        let (longest, shortest) = match *self {
+            Overlap::Fixed(length) => (length, length),
+            Overlap::Adaptive => {
+                let shorter = shorter();
+                let length = match shorter {
+                    ..=5 => 2,
+                    6..=9 => 3,
+                    _ => 4,
+                };
+                let length = length.min(shorter).max(1);
+                (length, length)
+            },
+            Overlap::Longest(length) => (shorter(), length),
+        };
+        (shortest..=longest).rev()
+    }
+}
+
+impl Default for Overlap {
+    fn default() -> Self {
+        Overlap::Fixed(3)
+    }
+}
@@ -0,0 +1,264 @@
+use alloc::{collections::BTreeSet, vec, vec::Vec};
+use core::mem;
+
+use crate::{Blend, Portmanteau, PreparedWord};
+
+/// A list of words, indexed so the best words to blend with a given word can
+/// be found without trying every word in the list
+///
+/// Words are indexed by the runs of letters they could be joined on (trios by
+/// default, see [`Overlap`](crate::Overlap)) and the vowels they could be
+/// joined on. Only words that share one of these with the given word are
+/// blended, using the generator the index was made with, and the blends are
+/// ranked in two tiers: words that share a run of letters come before words
+/// that only share a vowel (which is most words), however the generator's
+/// [`Scorer`](crate::Scorer) rates them, and the latter are only blended if
+/// there aren't enough of the former. Each tier is ranked by the scorer
+///
+/// Words that can only be joined some other way (e.g. by
+/// [`FallbackVowels`](crate::FallbackVowels), or a custom
+/// [`Strategy`](crate::Strategy)) won't be found
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{PartnerIndex, Portmanteau};
+///
+/// let words = ["promise", "slinky", "hotel", "captain", "chromosome"];
+/// let index = PartnerIndex::new(&Portmanteau::default(), words);
+///
+/// let partners = index.right_partners("chrome", 2);
+/// assert_eq!(partners[0], "chromise");
+/// assert_eq!(partners[0].right_word(), "promise");
+///
+/// let partners = index.left_partners("acceptable", 1);
+/// assert_eq!(partners[0], "captable");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PartnerIndex<'a> {
+    generator: Portmanteau,
+    words: Vec<PreparedWord<'a>>,
+    /// Every run of letters that can be joined on when the word is on the
+    /// left, as (word, run) sorted by the letters of the run
+    left_runs: Vec<(usize, usize)>,
+    /// The same for runs that can be joined on when the word is on the right
+    right_runs: Vec<(usize, usize)>,
+    /// For each vowel of the generator's language, the words that can be
+    /// joined on it when they're on the left
+    left_vowels: Vec<Vec<usize>>,
+    /// The same for words on the right
+    right_vowels: Vec<Vec<usize>>,
+}
+
+impl<'a> PartnerIndex<'a> {
+    /// Indexes a list of words, to be blended with the given generator
+    ///
+    /// Invalid words (see [`PortmanteauError`](crate::PortmanteauError)) and
+    /// repeats are left out
+    pub fn new<I: IntoIterator<Item = &'a str>>(
+        generator: &Portmanteau,
+        words: I,
+    ) -> Self {
+        let mut seen = BTreeSet::new();
+        let words = words
+            .into_iter()
+            .filter(|word| seen.insert(*word))
+            .map(|word| generator.prepare(word))
+            .filter(PreparedWord::is_valid)
+            .collect::<Vec<_>>();
+
+        let length = generator.overlap_length();
+        let mut left_runs = Vec::new();
+        let mut right_runs = Vec::new();
+        let vowel_count = generator.language().all_vowels().count();
+        let mut left_vowels = vec![Vec::new(); vowel_count];
+        let mut right_vowels = vec![Vec::new(); vowel_count];
+        for (id, word) in words.iter().enumerate() {
+            // The first run of the left word can't be joined on
+            left_runs.extend((1..word.ngrams().count(length)).map(|n| (id, n)));
+            let (_, index) = word.ngram_index();
+            right_runs.extend(index.iter().map(|n| (id, *n)));
+
+            for (vowels, found) in [
+                (&mut left_vowels, &word.vowels_rtl),
+                (&mut right_vowels, &word.vowels_ltr),
+            ] {
+                for (slot, index) in found.iter().enumerate() {
+                    if index.is_some() {
+                        vowels[slot].push(id);
+                    }
+                }
+            }
+        }
+        let by_letters = |a: &(usize, usize), b: &(usize, usize)| {
+            let run =
+                |(id, n): (usize, usize)| words[id].ngrams().get(n, length);
+            run(*a).cmp(run(*b))
+        };
+        left_runs.sort_unstable_by(by_letters);
+        right_runs.sort_unstable_by(by_letters);
+
+        PartnerIndex {
+            generator: generator.clone(),
+            words,
+            left_runs,
+            right_runs,
+            left_vowels,
+            right_vowels,
+        }
+    }
+
+    /// The generator used to blend words
+    pub fn generator(&self) -> &Portmanteau {
+        &self.generator
+    }
+
+    /// The number of words indexed
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether no words were indexed
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// The best (up to) `count` words to go on the right of the given word,
+    /// blended with it, ranked in tiers (see [`PartnerIndex`])
+    pub fn right_partners<'s>(
+        &'s self,
+        word: &'s str,
+        count: usize,
+    ) -> Vec<Blend<'s>> {
+        let word = self.generator.prepare(word);
+        self.tiered_partners(&word, count, true)
+    }
+
+    /// The best (up to) `count` words to go on the left of the given word,
+    /// blended with it, ranked in tiers (see [`PartnerIndex`])
+    pub fn left_partners<'s>(
+        &'s self,
+        word: &'s str,
+        count: usize,
+    ) -> Vec<Blend<'s>> {
+        let word = self.generator.prepare(word);
+        self.tiered_partners(&word, count, false)
+    }
+
+    /// Blends the words that share a run of letters with the given word, then
+    /// (if there aren't enough) the words that share a vowel, ranking each
+    /// tier by score on its own
+    fn tiered_partners<'s>(
+        &'s self,
+        word: &PreparedWord<'s>,
+        count: usize,
+        word_on_left: bool,
+    ) -> Vec<Blend<'s>> {
+        if !word.is_valid() || count == 0 {
+            return Vec::new();
+        }
+
+        // Words already blended
+        let mut tried = vec![false; self.words.len()];
+        let mut partners = self.ranked(
+            word,
+            self.sharing_runs(word, word_on_left),
+            word_on_left,
+            &mut tried,
+        );
+        partners.truncate(count);
+        if partners.len() < count {
+            let more = self.ranked(
+                word,
+                self.sharing_vowels(word, word_on_left),
+                word_on_left,
+                &mut tried,
+            );
+            partners.extend(more.into_iter().take(count - partners.len()));
+        }
+        partners
+    }
+
+    /// Blends the given word with each of the words not yet tried, best first
+    fn ranked<'s>(
+        &'s self,
+        word: &PreparedWord<'s>,
+        ids: Vec<usize>,
+        word_on_left: bool,
+        tried: &mut [bool],
+    ) -> Vec<Blend<'s>> {
+        let mut blends = ids
+            .into_iter()
+            .filter(|id| !mem::replace(&mut tried[*id], true))
+            .filter(|id| self.words[*id].lowercase() != word.lowercase())
+            .filter_map(|id| {
+                let partner = &self.words[id];
+                let blend = if word_on_left {
+                    self.generator.blend_prepared_in_order(word, partner)
+                } else {
+                    self.generator.blend_prepared_in_order(partner, word)
+                }?;
+                Some((self.generator.score(&blend), blend))
+            })
+            .collect::<Vec<_>>();
+        // Stable, so ties keep the order of the word list
+        blends.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+        blends.into_iter().map(|(_, blend)| blend).collect()
+    }
+
+    /// The words with a run of letters that could be joined to one of the
+    /// given word's, in the order they were indexed
+    fn sharing_runs(
+        &self,
+        word: &PreparedWord,
+        word_on_left: bool,
+    ) -> Vec<usize> {
+        let length = self.generator.overlap_length();
+        let ngrams = word.ngrams();
+        // The first run of the left word can't be joined on, and the index
+        // already leaves out the last two runs of the right word
+        let (left_runs, right_runs, others) = if word_on_left {
+            (1..ngrams.count(length), &[][..], &self.right_runs)
+        } else {
+            (0..0, word.ngram_index().1, &self.left_runs)
+        };
+
+        let mut found = BTreeSet::new();
+        for n in left_runs.chain(right_runs.iter().copied()) {
+            let run = ngrams.get(n, length);
+            let letters = |(id, n): &(usize, usize)| {
+                self.words[*id].ngrams().get(*n, length)
+            };
+            let start = others.partition_point(|other| letters(other) < run);
+            found.extend(
+                others[start..]
+                    .iter()
+                    .take_while(|other| letters(other) == run)
+                    .map(|(id, _)| *id),
+            );
+        }
+        found.into_iter().collect()
+    }
+
+    /// The words with a vowel that could be joined to one of the given
+    /// word's, in the order they were indexed
+    fn sharing_vowels(
+        &self,
+        word: &PreparedWord,
+        word_on_left: bool,
+    ) -> Vec<usize> {
+        let (vowels, others) = if word_on_left {
+            (&word.vowels_rtl, &self.right_vowels)
+        } else {
+            (&word.vowels_ltr, &self.left_vowels)
+        };
+        vowels
+            .iter()
+            .zip(others)
+            .filter(|(index, _)| index.is_some())
+            .flat_map(|(_, ids)| ids.iter().copied())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
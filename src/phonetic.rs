@@ -0,0 +1,333 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::cmp::Reverse;
+
+use crate::{JoinMethod, JoinPoints, Portmanteau, Strategy};
+
+/// Joins the words where they share a sound, even if it's spelled
+/// differently in each word (e.g. the "ph" in "graph" and the "f" in "fiction")
+///
+/// Words are turned into sounds by a set of letter-to-sound rules, and the
+/// longest run of sounds the words share is joined on. As with
+/// [`SharedTrios`](crate::SharedTrios), the start of the left word and the end
+/// of the right word are ignored, and runs nearer the end of the left word
+/// and the start of the right word are preferred. This strategy isn't used by
+/// default, add it with
+/// [`PortmanteauBuilder::strategy`](crate::PortmanteauBuilder::strategy)
+///
+/// After every shared run, vowel sounds that are said in a similar part of
+/// the mouth (e.g. the "o"s of "smoke" and "fog") are joined on, so words
+/// that don't share a sound can still be joined
+///
+/// The rules are an approximation of English spelling, so they won't be
+/// right for every word (or any other language)
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{JoinMethod, Phonemes, Portmanteau};
+///
+/// let generator = Portmanteau::builder()
+///     .clear_strategies()
+///     .strategy(Phonemes::english())
+///     .build();
+/// let blend = generator.blend("graph", "fiction").unwrap();
+/// assert_eq!(blend, "grafiction");
+/// assert_eq!(blend.method(), JoinMethod::SharedSound);
+///
+/// let generator = Portmanteau::builder()
+///     .min_word_size(3)
+///     .clear_strategies()
+///     .strategy(Phonemes::english())
+///     .build();
+/// assert_eq!(generator.generate("smoke", "fog").unwrap(), "smog");
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Phonemes {
+    rules: &'static [Rule],
+}
+
+impl Phonemes {
+    /// Letter-to-sound rules for English, using
+    /// [ARPABET](https://en.wikipedia.org/wiki/ARPABET) phonemes
+    pub const fn english() -> Self {
+        Phonemes { rules: ENGLISH }
+    }
+
+    /// The sounds of a word
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::Phonemes;
+    ///
+    /// let english = Phonemes::english();
+    /// assert_eq!(english.transcribe("knight"), ["N", "AY", "T"]);
+    /// assert_eq!(english.transcribe("phone"), ["F", "OW", "N"]);
+    /// ```
+    pub fn transcribe(&self, word: &str) -> Vec<&'static str> {
+        let word = word.to_lowercase();
+        self.sounds(&word)
+            .into_iter()
+            .map(|sound| sound.phoneme)
+            .collect()
+    }
+
+    /// The sounds of a lowercase word, and where they're spelled
+    fn sounds(&self, word: &str) -> Vec<Sound> {
+        let mut sounds = Vec::<Sound>::new();
+        let mut index = 0;
+        while let Some(letter) = word[index..].chars().next() {
+            // Double consonants only make one sound
+            if !is_vowel(letter)
+                && word[..index].ends_with(letter)
+                && !sounds.is_empty()
+            {
+                index += letter.len_utf8();
+                continue;
+            }
+
+            let rule = self.rules.iter().find(|rule| rule.applies(word, index));
+            let length =
+                rule.map_or(letter.len_utf8(), |rule| rule.letters.len());
+            for (n, phoneme) in
+                rule.map_or(&[][..], |rule| rule.sounds).iter().enumerate()
+            {
+                sounds.push(Sound {
+                    phoneme,
+                    index,
+                    // Only the first sound of a spelling can be joined on
+                    joinable: n == 0,
+                });
+            }
+            index += length;
+        }
+        sounds
+    }
+}
+
+impl Default for Phonemes {
+    fn default() -> Self {
+        Phonemes::english()
+    }
+}
+
+impl Strategy for Phonemes {
+    fn method(&self) -> JoinMethod {
+        JoinMethod::SharedSound
+    }
+
+    fn join_points<'a>(
+        &'a self,
+        _: &'a Portmanteau,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> JoinPoints<'a> {
+        let left_sounds = self.sounds(left_word);
+        let right_sounds = self.sounds(right_word);
+
+        // Every pair of sounds that start a shared run, with the length of the
+        // run, ignoring the first sound of the left word and the last sound of
+        // the right word. Similar vowel sounds are a run of no length
+        let mut runs = Vec::new();
+        for left in (1..left_sounds.len()).rev() {
+            for right in 0..right_sounds.len().saturating_sub(1) {
+                let (left_sound, right_sound) =
+                    (left_sounds[left], right_sounds[right]);
+                if !left_sound.joinable || !right_sound.joinable {
+                    continue;
+                }
+                let length = left_sounds[left..]
+                    .iter()
+                    .zip(&right_sounds[right..])
+                    .take_while(|(a, b)| a.phoneme == b.phoneme)
+                    .count();
+                if length > 0
+                    || vowel_class(left_sound.phoneme).is_some_and(|class| {
+                        vowel_class(right_sound.phoneme) == Some(class)
+                    })
+                {
+                    runs.push((length, (left_sound.index, right_sound.index)));
+                }
+            }
+        }
+        // Longest runs first, otherwise keeping the order above
+        runs.sort_by_key(|(length, _)| Reverse(*length));
+        Box::new(runs.into_iter().map(|(_, join_point)| join_point))
+    }
+}
+
+/// A sound in a word
+#[derive(Debug, Copy, Clone)]
+struct Sound {
+    phoneme: &'static str,
+    /// The byte index of the start of its spelling
+    index: usize,
+    /// Whether the word can be cut just before this sound
+    joinable: bool,
+}
+
+/// Letters, and the sounds they make in a certain position
+#[derive(Debug, Copy, Clone)]
+struct Rule {
+    letters: &'static str,
+    sounds: &'static [&'static str],
+    when: When,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum When {
+    Anywhere,
+    /// At the start of the word
+    Start,
+    /// At the end of the word
+    End,
+    /// Before "e", "i" or "y" (soft "c" and "g")
+    BeforeFront,
+    /// Before a consonant then a final "e", which lengthens the vowel (e.g.
+    /// "a" in "cake")
+    BeforeSilentE,
+}
+
+impl Rule {
+    fn applies(&self, word: &str, index: usize) -> bool {
+        let rest = &word[index..];
+        if !rest.starts_with(self.letters) {
+            return false;
+        }
+        let after = &rest[self.letters.len()..];
+        match self.when {
+            When::Anywhere => true,
+            When::Start => index == 0,
+            When::End => after.is_empty(),
+            When::BeforeFront => after.starts_with(['e', 'i', 'y']),
+            When::BeforeSilentE => {
+                let mut letters = after.chars();
+                letters.next().is_some_and(|letter| !is_vowel(letter))
+                    && letters.as_str() == "e"
+                    // "e" isn't the only vowel
+                    && index > 0
+            },
+        }
+    }
+}
+
+fn is_vowel(letter: char) -> bool {
+    matches!(letter, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// Where in the mouth a vowel sound is said
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum VowelClass {
+    Front,
+    Central,
+    Back,
+}
+
+/// The class of a vowel sound (by where a diphthong starts), or `None` for a
+/// consonant sound
+fn vowel_class(phoneme: &str) -> Option<VowelClass> {
+    match phoneme {
+        "IY" | "IH" | "EY" | "EH" | "AE" => Some(VowelClass::Front),
+        "AH" | "ER" | "AY" | "AW" => Some(VowelClass::Central),
+        "AA" | "AO" | "OW" | "UW" | "OY" => Some(VowelClass::Back),
+        _ => None,
+    }
+}
+
+macro_rules! rules {
+    ($($letters:literal $(@ $when:ident)? => [$($sound:literal),*]),* $(,)?) => {
+        &[$(Rule {
+            letters: $letters,
+            sounds: &[$($sound),*],
+            when: rules!(@when $($when)?),
+        }),*]
+    };
+    (@when) => { When::Anywhere };
+    (@when $when:ident) => { When::$when };
+}
+
+/// Tried in order, so longer spellings come before the letters in them
+const ENGLISH: &[Rule] = rules![
+    "tion" => ["SH", "AH", "N"],
+    "sion" => ["ZH", "AH", "N"],
+    "tch" => ["CH"],
+    "sch" => ["S", "K"],
+    "eau" => ["OW"],
+    "igh" => ["AY"],
+    "ch" => ["CH"],
+    "sh" => ["SH"],
+    "th" => ["TH"],
+    "ph" => ["F"],
+    "gh" => [],
+    "ck" => ["K"],
+    "ng" => ["NG"],
+    "qu" => ["K", "W"],
+    "wh" => ["W"],
+    "wr" @ Start => ["R"],
+    "kn" @ Start => ["N"],
+    "gn" @ Start => ["N"],
+    "ps" @ Start => ["S"],
+    "mb" @ End => ["M"],
+    "dg" => ["JH"],
+    "ee" => ["IY"],
+    "ea" => ["IY"],
+    "ie" => ["IY"],
+    "ey" @ End => ["IY"],
+    "ei" => ["EY"],
+    "ue" @ End => ["UW"],
+    "ui" => ["UW"],
+    "ai" => ["EY"],
+    "ay" => ["EY"],
+    "ey" => ["EY"],
+    "oa" => ["OW"],
+    "oo" => ["UW"],
+    "ou" => ["AW"],
+    "ow" => ["OW"],
+    "oi" => ["OY"],
+    "oy" => ["OY"],
+    "au" => ["AO"],
+    "aw" => ["AO"],
+    "ew" => ["UW"],
+    "ar" => ["AA", "R"],
+    "er" => ["ER"],
+    "ir" => ["ER"],
+    "ur" => ["ER"],
+    "or" => ["AO", "R"],
+    "a" @ BeforeSilentE => ["EY"],
+    "e" @ BeforeSilentE => ["IY"],
+    "i" @ BeforeSilentE => ["AY"],
+    "o" @ BeforeSilentE => ["OW"],
+    "u" @ BeforeSilentE => ["UW"],
+    "y" @ BeforeSilentE => ["AY"],
+    "e" @ End => [],
+    "y" @ Start => ["Y"],
+    "y" @ End => ["IY"],
+    "y" => ["IH"],
+    "c" @ BeforeFront => ["S"],
+    "g" @ BeforeFront => ["JH"],
+    "x" => ["K", "S"],
+    "a" => ["AE"],
+    "e" => ["EH"],
+    "i" => ["IH"],
+    "o" => ["AA"],
+    "u" => ["AH"],
+    "b" => ["B"],
+    "c" => ["K"],
+    "d" => ["D"],
+    "f" => ["F"],
+    "g" => ["G"],
+    "h" => ["HH"],
+    "j" => ["JH"],
+    "k" => ["K"],
+    "l" => ["L"],
+    "m" => ["M"],
+    "n" => ["N"],
+    "p" => ["P"],
+    "q" => ["K"],
+    "r" => ["R"],
+    "s" => ["S"],
+    "t" => ["T"],
+    "v" => ["V"],
+    "w" => ["W"],
+    "z" => ["Z"],
+];
@@ -0,0 +1,179 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{
+    fold_case, letter_boundaries, strategy::Ngrams, unfold, Blend, Portmanteau,
+    VowelMap,
+};
+
+/// A word that's been checked and analysed by a generator ahead of time, so
+/// it can be blended with lots of other words without repeating the work
+///
+/// Make one with [`Portmanteau::prepare`], and blend two with
+/// [`Portmanteau::blend_prepared`]. This is worth doing when each word is
+/// blended many times, e.g. every word of a list with every other word. A
+/// prepared word should only be used with the generator that prepared it (or
+/// one with the same settings), otherwise the results may be wrong
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::Portmanteau;
+///
+/// let generator = Portmanteau::default();
+/// let words = ["liquid", "slinky", "chrome", "promise"]
+///     .iter()
+///     .map(|word| generator.prepare(word))
+///     .collect::<Vec<_>>();
+///
+/// for left_word in &words {
+///     for right_word in &words {
+///         assert_eq!(
+///             generator.blend_prepared(left_word, right_word),
+///             generator.blend(left_word.word(), right_word.word())
+///         );
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreparedWord<'a> {
+    word: &'a str,
+    lowercase: Cow<'a, str>,
+    valid: bool,
+    /// See [`letter_boundaries`]
+    boundaries: Vec<usize>,
+    /// Searched from the right, for when this is the left word
+    pub(crate) vowels_rtl: VowelMap,
+    /// Searched from the left, for when this is the right word
+    pub(crate) vowels_ltr: VowelMap,
+    /// See [`Ngrams::index`], for the generator's overlap length
+    index: Vec<usize>,
+    index_length: usize,
+}
+
+impl<'a> PreparedWord<'a> {
+    /// The word that was prepared
+    pub fn word(&self) -> &'a str {
+        self.word
+    }
+
+    /// The word in lowercase, which is what strategies are given
+    pub fn lowercase(&self) -> &str {
+        &self.lowercase
+    }
+
+    /// Whether the word can be blended, see
+    /// [`PortmanteauError`](crate::PortmanteauError) for why it might not be
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    pub(crate) fn ngrams(&self) -> Ngrams<'_> {
+        Ngrams::new(&self.lowercase, &self.boundaries)
+    }
+
+    /// The run length the index was made for, and the index itself
+    pub(crate) fn ngram_index(&self) -> (usize, &[usize]) {
+        (self.index_length, &self.index)
+    }
+
+    /// Turns a byte index into the lowercase word into the same position in
+    /// the original word
+    fn unfold(&self, index: usize) -> usize {
+        unfold(&self.lowercase, self.word, index)
+    }
+}
+
+impl Portmanteau {
+    /// Checks and analyses a word ahead of time, to be blended with
+    /// [`Portmanteau::blend_prepared`]
+    ///
+    /// See [`PreparedWord`] for more details
+    pub fn prepare<'a>(&self, word: &'a str) -> PreparedWord<'a> {
+        let word = self.word_of(word);
+        let valid = self.validate(word);
+        let lowercase = fold_case(word);
+        let index_length = self.overlap_length();
+        if !valid {
+            // Nothing else is used
+            return PreparedWord {
+                word,
+                lowercase,
+                valid,
+                boundaries: Vec::new(),
+                vowels_rtl: VowelMap(Vec::new()),
+                vowels_ltr: VowelMap(Vec::new()),
+                index: Vec::new(),
+                index_length,
+            };
+        }
+
+        let boundaries = letter_boundaries(&lowercase);
+        let index = Ngrams::new(&lowercase, &boundaries).index(index_length);
+        PreparedWord {
+            word,
+            valid,
+            vowels_rtl: self.vowel_map_rtl(&lowercase),
+            vowels_ltr: self.vowel_map_ltr(&lowercase),
+            boundaries,
+            index,
+            index_length,
+            lowercase,
+        }
+    }
+
+    /// Creates a portmanteau of two prepared words if possible, using this
+    /// generator's settings, and describes how it was made
+    ///
+    /// This gives the same result as [`Portmanteau::blend`] with the original
+    /// words, but skips the work done when the words were prepared. See
+    /// [`PreparedWord`] for more details
+    pub fn blend_prepared<'a>(
+        &self,
+        left_word: &PreparedWord<'a>,
+        right_word: &PreparedWord<'a>,
+    ) -> Option<Blend<'a>> {
+        if self.either_order {
+            self.better_order(
+                self.blend_prepared_in_order(left_word, right_word),
+                self.blend_prepared_in_order(right_word, left_word),
+            )
+            .map(|(blend, _)| blend)
+        } else {
+            self.blend_prepared_in_order(left_word, right_word)
+        }
+    }
+
+    /// [`Portmanteau::blend_prepared`] with the left word on the left, even if
+    /// trying either order
+    pub(crate) fn blend_prepared_in_order<'a>(
+        &self,
+        left_word: &PreparedWord<'a>,
+        right_word: &PreparedWord<'a>,
+    ) -> Option<Blend<'a>> {
+        if !(left_word.valid && right_word.valid) {
+            return None;
+        }
+
+        let blend = self.first_blend(
+            left_word.lowercase(),
+            right_word.lowercase(),
+            |strategy, visit| {
+                strategy
+                    .prepared_join_points(self, left_word, right_word, visit)
+            },
+            None,
+        )?;
+        let (left_index, right_index, method) =
+            (blend.left_index(), blend.right_index(), blend.method());
+        Some(
+            Blend::new(
+                left_word.word,
+                left_word.unfold(left_index),
+                right_word.word,
+                right_word.unfold(right_index),
+                method,
+            )
+            .with_casing(self.casing(&[left_word.word, right_word.word])),
+        )
+    }
+}
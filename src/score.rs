@@ -0,0 +1,207 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Blend, LanguageProfile, Portmanteau};
+
+/// A way of rating how good a portmanteau is
+///
+/// [`Portmanteau::best_blend`] scores every candidate with the generator's
+/// scorer, and picks the highest scoring one. The default scorer is
+/// [`WeightedScorer`]
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{Blend, Portmanteau, Scorer};
+///
+/// /// Prefers the shortest portmanteau
+/// #[derive(Debug)]
+/// struct Shortest;
+///
+/// impl Scorer for Shortest {
+///     fn score(&self, _: &Portmanteau, blend: &Blend) -> f64 {
+///         -(blend.len() as f64)
+///     }
+/// }
+///
+/// let generator = Portmanteau::builder().scorer(Shortest).build();
+/// let blend = generator.best_blend("acceptable", "captain").unwrap();
+/// assert_eq!(blend, "acceptain");
+/// ```
+pub trait Scorer: fmt::Debug + Send + Sync {
+    /// How good the blend is, higher is better
+    ///
+    /// The generator is given so the scorer can make use of its settings
+    fn score(&self, generator: &Portmanteau, blend: &Blend) -> f64;
+}
+
+/// Scores a blend on several measures, and combines them using the given
+/// weights
+///
+/// Each measure is between 0 and 1, as is the combined score:
+///
+/// * `overlap`: how many letters the words share where they're joined, up to
+///   the generator's overlap length
+/// * `retained`: how much is kept of the word that loses the most letters
+///   (shared letters count towards both words)
+/// * `pronounceability`: whether the join makes a longer run of consonants
+///   (or vowels) than either input word has
+/// * `length`: how close the portmanteau is to the average length of the input
+///   words
+/// * `whole_word`: whether either input word is kept whole, only if the
+///   generator embeds words (see
+///   [`PortmanteauBuilder::embed_words`](crate::PortmanteauBuilder::embed_words)),
+///   so those blends are preferred
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{blend, Portmanteau, Scorer, WeightedScorer};
+///
+/// let generator = Portmanteau::default();
+/// let scorer = WeightedScorer::default();
+/// let chromise = blend("chrome", "promise").unwrap();
+/// let liquinky = blend("liquid", "slinky").unwrap();
+/// assert!(
+///     scorer.score(&generator, &chromise) > scorer.score(&generator, &liquinky)
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WeightedScorer {
+    /// The weight of the letters shared at the join (default: 1)
+    pub overlap: f64,
+    /// The weight of how much of each word is kept (default: 1)
+    pub retained: f64,
+    /// The weight of how pronounceable the join is (default: 1)
+    pub pronounceability: f64,
+    /// The weight of how close the length is to the input words' (default:
+    /// 0.5)
+    pub length: f64,
+    /// The weight of keeping either word whole, if the generator embeds words
+    /// (default: 1)
+    pub whole_word: f64,
+}
+
+impl Default for WeightedScorer {
+    fn default() -> Self {
+        WeightedScorer {
+            overlap: 1.0,
+            retained: 1.0,
+            pronounceability: 1.0,
+            length: 0.5,
+            whole_word: 1.0,
+        }
+    }
+}
+
+impl Scorer for WeightedScorer {
+    fn score(&self, generator: &Portmanteau, blend: &Blend) -> f64 {
+        let whole_word_weight = if generator.embeds_words() {
+            self.whole_word
+        } else {
+            0.0
+        };
+        let total_weight = self.overlap
+            + self.retained
+            + self.pronounceability
+            + self.length
+            + whole_word_weight;
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+
+        let left_length = blend.left_word().chars().count() as f64;
+        let right_length = blend.right_word().chars().count() as f64;
+        let left_kept = blend.left_fragment().chars().count();
+        let right_kept = blend.right_fragment().chars().count();
+        let (before, after) = shared_at_join(blend);
+
+        let overlap = (before + after).min(generator.overlap_length()) as f64
+            / generator.overlap_length() as f64;
+        let retained = ((left_kept + after) as f64 / left_length)
+            .min((right_kept + before) as f64 / right_length)
+            .min(1.0);
+        let pronounceability = pronounceability(generator.language(), blend);
+        let average_length = (left_length + right_length) / 2.0;
+        let length = (1.0
+            - ((left_kept + right_kept) as f64 - average_length).abs()
+                / average_length)
+            .max(0.0);
+        let whole_word = if (left_kept + after) as f64 == left_length
+            || (right_kept + before) as f64 == right_length
+        {
+            1.0
+        } else {
+            0.0
+        };
+
+        (self.overlap * overlap
+            + self.retained * retained
+            + self.pronounceability * pronounceability
+            + self.length * length
+            + whole_word_weight * whole_word)
+            / total_weight
+    }
+}
+
+/// How many letters either side of the join are in both words, i.e. the end
+/// of the left fragment also comes just before the right fragment in the right
+/// word, or the start of the right fragment also comes just after the left
+/// fragment in the left word
+pub(crate) fn shared_at_join(blend: &Blend) -> (usize, usize) {
+    fn same(a: char, b: char) -> bool {
+        a.to_lowercase().eq(b.to_lowercase())
+    }
+
+    let left_word = blend.left_word();
+    let right_word = blend.right_word();
+    let before = blend
+        .left_fragment()
+        .chars()
+        .rev()
+        .zip(right_word[..blend.right_index()].chars().rev())
+        .take_while(|(a, b)| same(*a, *b))
+        .count();
+    let after = blend
+        .right_fragment()
+        .chars()
+        .zip(left_word[blend.left_index()..].chars())
+        .take_while(|(a, b)| same(*a, *b))
+        .count();
+    (before, after)
+}
+
+/// 1 if the run of consonants or vowels across the join is no longer than the
+/// longest run in either input word, getting lower the longer it is
+fn pronounceability(language: &LanguageProfile, blend: &Blend) -> f64 {
+    let classes = |word: &str| {
+        word.chars()
+            .map(|letter| language.is_vowel(letter))
+            .collect::<Vec<_>>()
+    };
+    let longest_run = |classes: &[bool]| {
+        classes
+            .chunk_by(|a, b| a == b)
+            .map(<[bool]>::len)
+            .max()
+            .unwrap_or(0)
+    };
+
+    let left = classes(blend.left_fragment());
+    let right = classes(blend.right_fragment());
+    let (Some(end), Some(start)) = (left.last(), right.first()) else {
+        return 1.0;
+    };
+    if end != start {
+        return 1.0;
+    }
+    let join_run = left.iter().rev().take_while(|class| *class == end).count()
+        + right.iter().take_while(|class| *class == start).count();
+    let allowed = longest_run(&classes(blend.left_word()))
+        .max(longest_run(&classes(blend.right_word())));
+    if join_run <= allowed {
+        1.0
+    } else {
+        allowed as f64 / join_run as f64
+    }
+}
@@ -0,0 +1,547 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt, ops::ControlFlow};
+
+use crate::{
+    letter_boundaries, JoinMethod, OptionExt, Portmanteau, PreparedWord,
+};
+
+/// Index pairs to join two words at, as produced by a [`Strategy`]
+pub type JoinPoints<'a> = Box<dyn Iterator<Item = (usize, usize)> + 'a>;
+
+/// A way of finding where two words could be joined
+///
+/// A [`Portmanteau`] generator tries each of its strategies in order, taking
+/// the join points from the first strategy until they run out, then moving
+/// onto the next. The built-in strategies are [`SharedTrios`],
+/// [`MatchingVowels`], and [`FallbackVowels`], which is also the default order
+///
+/// A join point is a pair of byte indices, `(left_index, right_index)`. The
+/// portmanteau is made from the left word up to (but not including)
+/// `left_index`, followed by the right word from `right_index` onwards. Join
+/// points that aren't on character boundaries, or would make a portmanteau
+/// that's part of an input word (see
+/// [`PortmanteauBuilder::reject_substrings`](crate::PortmanteauBuilder::reject_substrings)),
+/// are skipped
+///
+/// Strategies are only given words that have already been validated by the
+/// generator
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{JoinMethod, JoinPoints, Portmanteau, Strategy};
+///
+/// /// Joins the words on the same letter, as close to the middle of each
+/// /// word as possible
+/// #[derive(Debug)]
+/// struct SameLetter;
+///
+/// impl Strategy for SameLetter {
+///     fn method(&self) -> JoinMethod {
+///         JoinMethod::Custom("same letter")
+///     }
+///
+///     fn join_points<'a>(
+///         &'a self,
+///         _: &'a Portmanteau,
+///         left_word: &'a str,
+///         right_word: &'a str,
+///     ) -> JoinPoints<'a> {
+///         let middle = left_word.len() / 2;
+///         Box::new(
+///             left_word
+///                 .char_indices()
+///                 .skip_while(move |(left_index, _)| *left_index < middle)
+///                 .flat_map(move |(left_index, letter)| {
+///                     right_word
+///                         .find(letter)
+///                         .map(|right_index| (left_index, right_index))
+///                 }),
+///         )
+///     }
+/// }
+///
+/// let generator = Portmanteau::builder()
+///     .clear_strategies()
+///     .strategy(SameLetter)
+///     .build();
+/// let blend = generator.blend("liquid", "slinky").unwrap();
+/// assert_eq!(blend, "liquinky");
+/// assert_eq!(blend.method(), JoinMethod::Custom("same letter"));
+/// ```
+pub trait Strategy: fmt::Debug + Send + Sync {
+    /// How blends made by this strategy are described
+    fn method(&self) -> JoinMethod;
+
+    /// Every index pair to try joining the words at, in order of preference
+    ///
+    /// The generator is given so the strategy can make use of its settings
+    fn join_points<'a>(
+        &'a self,
+        generator: &'a Portmanteau,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> JoinPoints<'a>;
+
+    /// Gives `visit` every index pair to try joining the words at, in order
+    /// of preference, stopping as soon as it breaks
+    ///
+    /// By default this is the same as [`Strategy::join_points`], but it can be
+    /// overridden to avoid allocating
+    fn visit_join_points(
+        &self,
+        generator: &Portmanteau,
+        left_word: &str,
+        right_word: &str,
+        visit: &mut dyn FnMut((usize, usize)) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        self.join_points(generator, left_word, right_word)
+            .try_for_each(visit)
+    }
+
+    /// Gives `visit` every index pair to try joining two prepared words at,
+    /// in order of preference, stopping as soon as it breaks
+    ///
+    /// The indices are into the lowercase words. By default this is the same
+    /// as [`Strategy::join_points`] with [`PreparedWord::lowercase`], but it
+    /// can be overridden to make use of work already done when the words were
+    /// prepared, and to avoid allocating
+    fn prepared_join_points(
+        &self,
+        generator: &Portmanteau,
+        left_word: &PreparedWord<'_>,
+        right_word: &PreparedWord<'_>,
+        visit: &mut dyn FnMut((usize, usize)) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        self.join_points(
+            generator,
+            left_word.lowercase(),
+            right_word.lowercase(),
+        )
+        .try_for_each(visit)
+    }
+}
+
+/// Joins the words where they share a run of letters (trios by default, see
+/// [`PortmanteauBuilder::overlap`](crate::PortmanteauBuilder::overlap))
+///
+/// The start of the left word and the end of the right word are ignored, and
+/// trios nearer the end of the left word and the start of the right word are
+/// preferred, as these give longer portmanteaux. If more than one length of
+/// run is allowed, longer runs are preferred
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SharedTrios;
+
+impl Strategy for SharedTrios {
+    fn method(&self) -> JoinMethod {
+        JoinMethod::SharedTrio
+    }
+
+    fn join_points<'a>(
+        &'a self,
+        generator: &'a Portmanteau,
+        a: &'a str,
+        b: &'a str,
+    ) -> JoinPoints<'a> {
+        let min_word_size = generator.min_word_size();
+        debug_assert!(
+            a.chars().count() >= min_word_size
+                && b.chars().count() >= min_word_size,
+            "Less than {} letter words should have already been eliminated",
+            min_word_size
+        );
+        let mut join_points = Vec::new();
+        // Every join point is collected, so this never stops early
+        let _ = self.visit_join_points(generator, a, b, &mut |join_point| {
+            join_points.push(join_point);
+            ControlFlow::Continue(())
+        });
+        Box::new(join_points.into_iter())
+    }
+
+    fn visit_join_points(
+        &self,
+        generator: &Portmanteau,
+        a: &str,
+        b: &str,
+        visit: &mut dyn FnMut((usize, usize)) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        with_ngrams(a, |a| {
+            with_ngrams(b, |b| shared_runs(generator, a, b, None, visit))
+        })
+    }
+
+    fn prepared_join_points(
+        &self,
+        generator: &Portmanteau,
+        left_word: &PreparedWord<'_>,
+        right_word: &PreparedWord<'_>,
+        visit: &mut dyn FnMut((usize, usize)) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        shared_runs(
+            generator,
+            left_word.ngrams(),
+            right_word.ngrams(),
+            Some(right_word.ngram_index()),
+            visit,
+        )
+    }
+}
+
+/// Words with up to this many letters have their letter boundaries kept on
+/// the stack
+const STACK_LETTERS: usize = 64;
+
+/// Below this many runs in the right word, every run of the left word is
+/// compared with every run of the right word, which is quicker than sorting
+/// the right word's runs to look them up
+const SCAN_LIMIT: usize = 48;
+
+/// Calls `f` with the word's runs of letters, only working out where each
+/// letter starts if the word isn't ASCII, and only allocating if it's also
+/// long
+fn with_ngrams<R>(word: &str, f: impl FnOnce(Ngrams) -> R) -> R {
+    if word.is_ascii() {
+        return f(Ngrams::ascii(word));
+    }
+    let mut buffer = [0; STACK_LETTERS + 1];
+    let mut letters = 0;
+    for (index, _) in word.char_indices() {
+        if letters == STACK_LETTERS {
+            return f(Ngrams::new(word, &letter_boundaries(word)));
+        }
+        buffer[letters] = index;
+        letters += 1;
+    }
+    buffer[letters] = word.len();
+    f(Ngrams::new(word, &buffer[..=letters]))
+}
+
+/// Gives `visit` every join point where the words share a run of letters, in
+/// order of preference, until it breaks
+///
+/// `cached` is an index of `b` (see [`Ngrams::index`]) for a length of run no
+/// longer than any that's looked for, which is used rather than working it out
+/// again
+fn shared_runs(
+    generator: &Portmanteau,
+    a: Ngrams,
+    b: Ngrams,
+    cached: Option<(usize, &[usize])>,
+    visit: &mut dyn FnMut((usize, usize)) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    // The lengths of run to look for, longest first, which are consecutive
+    let shorter = a.letters().min(b.letters());
+    let (longest, shortest) = match generator
+        .overlap()
+        .lengths(a.word, b.word)
+        .filter(|length| *length <= shorter)
+        .fold(None, |range, length| {
+            Some((range.map_or(length, |(longest, _)| longest), length))
+        }) {
+        Some(range) => range,
+        None => return ControlFlow::Continue(()),
+    };
+    // Shorter runs are also found inside longer ones
+    let found_longer = |a_n: usize, b_n: usize, length: usize| {
+        (length + 1..=longest).any(|longer| {
+            a_n < a.count(longer)
+                && b_n + 2 < b.count(longer)
+                && a.bytes(a_n, longer) == b.bytes(b_n, longer)
+        })
+    };
+
+    // Short words are compared run by run. Otherwise, runs of every length are
+    // looked up in an index by their first `key_length` letters
+    let computed;
+    let (key_length, b_index) = match cached {
+        Some((key_length, index)) if key_length <= shortest => {
+            (key_length, index)
+        },
+        _ if b.count(shortest).saturating_sub(2) <= SCAN_LIMIT => {
+            return scan_runs(a, b, (longest, shortest), found_longer, visit);
+        },
+        _ => {
+            computed = b.index(shortest);
+            (shortest, &computed[..])
+        },
+    };
+    for length in (shortest..=longest).rev() {
+        // .rev here and the sorted b_index prioritises finding longer
+        // portmanteaux by searching from the end of `a` and the start of `b`
+        for a_n in (1..a.count(length)).rev() {
+            let key = a.bytes(a_n, key_length);
+            let run = a.bytes(a_n, length);
+            let start =
+                b_index.partition_point(|b_n| b.bytes(*b_n, key_length) < key);
+            for b_n in b_index[start..]
+                .iter()
+                .take_while(|b_n| b.bytes(**b_n, key_length) == key)
+            {
+                if b_n + 2 < b.count(length)
+                    && b.bytes(*b_n, length) == run
+                    && !found_longer(a_n, *b_n, length)
+                {
+                    visit((a.start(a_n), b.start(*b_n)))?;
+                }
+            }
+        }
+    }
+    ControlFlow::Continue(())
+}
+
+/// [`shared_runs`] for when `b` has few enough runs to compare every run of
+/// `a` with
+fn scan_runs(
+    a: Ngrams,
+    b: Ngrams,
+    (longest, shortest): (usize, usize),
+    found_longer: impl Fn(usize, usize, usize) -> bool,
+    visit: &mut dyn FnMut((usize, usize)) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    for length in (shortest..=longest).rev() {
+        let b_runs = b.count(length).saturating_sub(2);
+        for a_n in (1..a.count(length)).rev() {
+            let run = a.bytes(a_n, length);
+            for b_n in 0..b_runs {
+                let b_run = b.bytes(b_n, length);
+                // Most runs can be told apart by their first letter, which is
+                // quicker to compare
+                if b_run.first() == run.first()
+                    && b_run == run
+                    && !found_longer(a_n, b_n, length)
+                {
+                    visit((a.start(a_n), b.start(b_n)))?;
+                }
+            }
+        }
+    }
+    ControlFlow::Continue(())
+}
+
+/// A word and where each of its letters start (see
+/// [`letter_boundaries`](crate::letter_boundaries)), for looking up runs of
+/// letters
+///
+/// ASCII words have no boundaries, as every letter is one byte
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Ngrams<'a> {
+    word: &'a str,
+    boundaries: Option<&'a [usize]>,
+}
+
+impl<'a> Ngrams<'a> {
+    pub(crate) fn new(word: &'a str, boundaries: &'a [usize]) -> Self {
+        Ngrams {
+            word,
+            boundaries: Some(boundaries),
+        }
+    }
+
+    /// An ASCII word's runs of letters
+    fn ascii(word: &'a str) -> Self {
+        debug_assert!(word.is_ascii());
+        Ngrams {
+            word,
+            boundaries: None,
+        }
+    }
+
+    /// The number of letters in the word
+    fn letters(&self) -> usize {
+        self.boundaries
+            .map_or(self.word.len(), |boundaries| boundaries.len() - 1)
+    }
+
+    /// The byte index the `n`th letter starts at, or the length of the word
+    /// if there are `n` letters
+    fn boundary(&self, n: usize) -> usize {
+        self.boundaries.map_or(n, |boundaries| boundaries[n])
+    }
+
+    /// The number of runs of `length` letters in the word
+    pub(crate) fn count(&self, length: usize) -> usize {
+        (self.letters() + 1).saturating_sub(length)
+    }
+
+    /// The `n`th run of `length` letters
+    pub(crate) fn get(&self, n: usize, length: usize) -> &'a str {
+        &self.word[self.boundary(n)..self.boundary(n + length)]
+    }
+
+    /// The bytes of the `n`th run of `length` letters, which compare the same
+    /// way as its letters
+    fn bytes(&self, n: usize, length: usize) -> &'a [u8] {
+        &self.word.as_bytes()[self.boundary(n)..self.boundary(n + length)]
+    }
+
+    /// The byte index the `n`th run starts at
+    fn start(&self, n: usize) -> usize {
+        self.boundary(n)
+    }
+
+    /// Which runs of `length` letters can be joined onto when this is the
+    /// right word (all but the last two), sorted by their letters then by
+    /// position, so every position of a run can be found with a binary search
+    pub(crate) fn index(&self, length: usize) -> Vec<usize> {
+        let mut runs = (0..self.count(length).saturating_sub(2))
+            .map(|n| (self.bytes(n, length), n))
+            .collect::<Vec<_>>();
+        runs.sort_unstable();
+        runs.into_iter().map(|(_, n)| n).collect()
+    }
+}
+
+/// Joins the words on the same vowel
+///
+/// For each vowel (in the order given to the generator), the rightmost
+/// occurrence in the left word is joined to the leftmost occurrence in the
+/// right word. The first letter of the left word and last letter of the right
+/// word are ignored (see
+/// [`PortmanteauBuilder::vowel_search_margin`](crate::PortmanteauBuilder::vowel_search_margin))
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MatchingVowels;
+
+impl Strategy for MatchingVowels {
+    fn method(&self) -> JoinMethod {
+        JoinMethod::MatchingVowel
+    }
+
+    fn join_points<'a>(
+        &'a self,
+        generator: &'a Portmanteau,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> JoinPoints<'a> {
+        Box::new(matching_vowels(
+            generator
+                .vowels_rtl(left_word)
+                .zip(generator.vowels_ltr(right_word)),
+        ))
+    }
+
+    fn visit_join_points(
+        &self,
+        generator: &Portmanteau,
+        left_word: &str,
+        right_word: &str,
+        visit: &mut dyn FnMut((usize, usize)) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        matching_vowels(
+            generator
+                .vowels_rtl(left_word)
+                .zip(generator.vowels_ltr(right_word)),
+        )
+        .try_for_each(visit)
+    }
+
+    fn prepared_join_points(
+        &self,
+        _: &Portmanteau,
+        left_word: &PreparedWord<'_>,
+        right_word: &PreparedWord<'_>,
+        visit: &mut dyn FnMut((usize, usize)) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        matching_vowels(vowel_pairs(left_word, right_word)).try_for_each(visit)
+    }
+}
+
+/// Where each vowel is in the prepared words, for joining them on vowels
+fn vowel_pairs<'a>(
+    left_word: &'a PreparedWord<'_>,
+    right_word: &'a PreparedWord<'_>,
+) -> impl Iterator<Item = (Option<usize>, Option<usize>)> + 'a {
+    left_word
+        .vowels_rtl
+        .iter()
+        .copied()
+        .zip(right_word.vowels_ltr.iter().copied())
+}
+
+/// See [`MatchingVowels`], given where each vowel is in the two words
+fn matching_vowels(
+    vowels: impl Iterator<Item = (Option<usize>, Option<usize>)>,
+) -> impl Iterator<Item = (usize, usize)> {
+    vowels.filter_map(|(left_vowel_index, right_vowel_index)| {
+        left_vowel_index.zip(right_vowel_index)
+    })
+}
+
+/// Joins the rightmost vowel of the left word to the leftmost vowel of the
+/// right word
+///
+/// This only gives one join point, and is intended as a last resort after
+/// [`MatchingVowels`]. If any vowels match, the last matching pair is used
+/// instead
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FallbackVowels;
+
+impl Strategy for FallbackVowels {
+    fn method(&self) -> JoinMethod {
+        JoinMethod::FallbackVowel
+    }
+
+    fn join_points<'a>(
+        &'a self,
+        generator: &'a Portmanteau,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> JoinPoints<'a> {
+        Box::new(
+            fallback_vowels(
+                generator
+                    .vowels_rtl(left_word)
+                    .zip(generator.vowels_ltr(right_word)),
+            )
+            .into_iter(),
+        )
+    }
+
+    fn visit_join_points(
+        &self,
+        generator: &Portmanteau,
+        left_word: &str,
+        right_word: &str,
+        visit: &mut dyn FnMut((usize, usize)) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        fallback_vowels(
+            generator
+                .vowels_rtl(left_word)
+                .zip(generator.vowels_ltr(right_word)),
+        )
+        .map_or(ControlFlow::Continue(()), visit)
+    }
+
+    fn prepared_join_points(
+        &self,
+        _: &Portmanteau,
+        left_word: &PreparedWord<'_>,
+        right_word: &PreparedWord<'_>,
+        visit: &mut dyn FnMut((usize, usize)) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        fallback_vowels(vowel_pairs(left_word, right_word))
+            .map_or(ControlFlow::Continue(()), visit)
+    }
+}
+
+/// See [`FallbackVowels`], given where each vowel is in the two words
+fn fallback_vowels(
+    vowels: impl Iterator<Item = (Option<usize>, Option<usize>)>,
+) -> Option<(usize, usize)> {
+    let mut chosen_left_vowel_index: Option<usize> = None;
+    let mut chosen_right_vowel_index: Option<usize> = None;
+    for (left_vowel_index, right_vowel_index) in vowels {
+        match (left_vowel_index, right_vowel_index) {
+            (Some(left_vowel_index), Some(right_vowel_index)) => {
+                chosen_left_vowel_index = Some(left_vowel_index);
+                chosen_right_vowel_index = Some(right_vowel_index);
+            },
+            (Some(left_index), None) => chosen_left_vowel_index
+                .replace_if(|inner| left_index > *inner, left_index),
+            (None, Some(right_index)) => chosen_right_vowel_index
+                .replace_if(|inner| right_index < *inner, right_index),
+            (None, None) => {},
+        }
+    }
+    chosen_left_vowel_index.zip(chosen_right_vowel_index)
+}
@@ -0,0 +1,320 @@
+use alloc::{
+    boxed::Box, collections::BTreeMap, string::String, sync::Arc, vec, vec::Vec,
+};
+use core::cmp::Reverse;
+
+use crate::{
+    fold_case, unfold_index, JoinMethod, JoinPoints, LanguageProfile,
+    Portmanteau, Strategy,
+};
+
+/// Joins the words where their syllables meet
+///
+/// The left word keeps its first syllables and the right word keeps its last
+/// syllables, preferring portmanteaus with about as many syllables as the
+/// input words have on average. Words with only one syllable can't be joined
+/// this way, so other strategies are needed too. This strategy isn't used by
+/// default, add it with
+/// [`PortmanteauBuilder::strategy`](crate::PortmanteauBuilder::strategy)
+///
+/// By default, words are split into syllables using the generator's
+/// [`LanguageProfile`]: each syllable has a vowel, and consonants between
+/// vowels start the next syllable if they're one of the language's onsets,
+/// otherwise they're split between the two. Hyphenation patterns (as used by
+/// TeX) can be used instead, see [`Syllables::from_patterns`] and
+/// [`Syllables::english_patterns`]
+///
+/// # Examples
+///
+/// ```
+/// use portmanteau::{JoinMethod, Portmanteau, Syllables};
+///
+/// let generator = Portmanteau::builder()
+///     .clear_strategies()
+///     .strategy(Syllables::new())
+///     .build();
+/// let blend = generator.blend("banana", "dinosaur").unwrap();
+/// assert_eq!(blend, "banasaur");
+/// assert_eq!(blend.method(), JoinMethod::SyllableBoundary);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Syllables {
+    patterns: Option<Arc<Patterns>>,
+}
+
+impl Syllables {
+    /// Splits words using the generator's language
+    pub fn new() -> Self {
+        Syllables::default()
+    }
+
+    /// Splits words using Liang's hyphenation algorithm with the given
+    /// patterns, in the format used by TeX's `\patterns` (separated by
+    /// whitespace, e.g. `"1ba a1b 2b1l"`)
+    ///
+    /// Each pattern is a run of letters with numbers between them, where `.`
+    /// marks the start or end of a word. Where patterns overlap, the highest
+    /// number wins, and words are split where the number is odd. Anything
+    /// after a `%` on a line is a comment. Patterns are matched against the
+    /// lowercase word
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::{LanguageProfile, Syllables};
+    ///
+    /// // Split between double letters
+    /// let syllables = Syllables::from_patterns("n1n s1s t1t");
+    /// let english = LanguageProfile::english();
+    /// assert_eq!(syllables.split(&english, "tennessee"), ["ten", "nes", "see"]);
+    /// ```
+    pub fn from_patterns(patterns: &str) -> Self {
+        Syllables {
+            patterns: Some(Arc::new(Patterns::parse(patterns))),
+        }
+    }
+
+    /// Splits words using TeX's US English hyphenation patterns (from the
+    /// hyph-utf8 package), which are embedded in the crate
+    ///
+    /// The patterns are parsed each time this is called, so keep (or clone)
+    /// the result rather than calling it for every word. Unlike the default
+    /// splitting, the generator's language isn't used
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::{LanguageProfile, Syllables};
+    ///
+    /// let syllables = Syllables::english_patterns();
+    /// let english = LanguageProfile::english();
+    /// assert_eq!(
+    ///     syllables.split(&english, "hyphenation"),
+    ///     ["hy", "phen", "ation"]
+    /// );
+    /// ```
+    pub fn english_patterns() -> Self {
+        let mut patterns = Patterns::parse(include_str!("hyph-en-us.txt"));
+        // The patterns are made for words split at least two letters from
+        // the start and three from the end, as TeX does for English
+        patterns.min_start = 2;
+        patterns.min_end = 3;
+        Syllables {
+            patterns: Some(Arc::new(patterns)),
+        }
+    }
+
+    /// Splits a word into syllables
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use portmanteau::{LanguageProfile, Syllables};
+    ///
+    /// let syllables = Syllables::new();
+    /// let english = LanguageProfile::english();
+    /// assert_eq!(syllables.split(&english, "banana"), ["ba", "na", "na"]);
+    /// assert_eq!(syllables.split(&english, "monster"), ["mon", "ster"]);
+    /// ```
+    pub fn split<'a>(
+        &self,
+        language: &LanguageProfile,
+        word: &'a str,
+    ) -> Vec<&'a str> {
+        let folded = fold_case(word);
+        let mut start = 0;
+        self.boundaries(language, &folded)
+            .into_iter()
+            .map(|index| unfold_index(&folded, word, index))
+            .chain(Some(word.len()))
+            .map(|end| {
+                let syllable = &word[start..end];
+                start = end;
+                syllable
+            })
+            .collect()
+    }
+
+    /// The byte indices where a lowercase word is split into syllables, not
+    /// including the start or end of the word
+    fn boundaries(&self, language: &LanguageProfile, word: &str) -> Vec<usize> {
+        match &self.patterns {
+            Some(patterns) => patterns.boundaries(word),
+            None => boundaries_by_onsets(language, word),
+        }
+    }
+}
+
+impl Strategy for Syllables {
+    fn method(&self) -> JoinMethod {
+        JoinMethod::SyllableBoundary
+    }
+
+    fn join_points<'a>(
+        &'a self,
+        generator: &'a Portmanteau,
+        left_word: &'a str,
+        right_word: &'a str,
+    ) -> JoinPoints<'a> {
+        let left_boundaries = self.boundaries(generator.language(), left_word);
+        let right_boundaries =
+            self.boundaries(generator.language(), right_word);
+        let total_syllables =
+            left_boundaries.len() + right_boundaries.len() + 2;
+        let right_syllables = right_boundaries.len() + 1;
+
+        let mut join_points = left_boundaries
+            .iter()
+            .enumerate()
+            .flat_map(|(left_cut, left_index)| {
+                right_boundaries.iter().enumerate().map(
+                    move |(right_cut, right_index)| {
+                        // Syllables kept from each word
+                        let left_kept = left_cut + 1;
+                        let right_kept = right_syllables - right_cut - 1;
+                        let kept = 2 * (left_kept + right_kept);
+                        (
+                            (
+                                kept.abs_diff(total_syllables),
+                                Reverse(left_kept),
+                            ),
+                            (*left_index, *right_index),
+                        )
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        join_points.sort_by_key(|(preference, _)| *preference);
+        Box::new(join_points.into_iter().map(|(_, join_point)| join_point))
+    }
+}
+
+/// Splits a word before each vowel, moving the split back over the longest
+/// run of consonants that's an onset
+pub(crate) fn boundaries_by_onsets(
+    language: &LanguageProfile,
+    word: &str,
+) -> Vec<usize> {
+    let letters = word.char_indices().collect::<Vec<_>>();
+    let mut vowels = vec![false; letters.len()];
+    for (n, (index, _)) in letters.iter().enumerate() {
+        for (vowel, semivowel) in language.all_vowels() {
+            if word[*index..].starts_with(vowel) && !(semivowel && n == 0) {
+                let length = vowel.chars().count();
+                vowels[n..n + length].iter_mut().for_each(|v| *v = true);
+            }
+        }
+    }
+
+    let mut boundaries = Vec::new();
+    // The letter after the end of the last vowel seen
+    let mut after_vowel = None;
+    for (n, is_vowel) in vowels.iter().enumerate() {
+        match (is_vowel, after_vowel) {
+            (true, Some(consonants)) if consonants < n => {
+                let start = letters[n].0;
+                let onset = (consonants..n)
+                    .map(|m| letters[m].0)
+                    .find(|&index| language.is_onset(&word[index..start]))
+                    .unwrap_or(start);
+                boundaries.push(onset);
+                after_vowel = Some(n + 1);
+            },
+            (true, _) => after_vowel = Some(n + 1),
+            (false, _) => {},
+        }
+    }
+
+    // A silent ending after a single consonant joins the syllable before
+    if let Some(&last) = boundaries.last() {
+        let single_consonant = letters
+            .iter()
+            .position(|(index, _)| *index == last)
+            .is_some_and(|n| n > 0 && vowels[n - 1]);
+        let silent = language.silent_endings().any(|ending| {
+            word.strip_suffix(ending).is_some_and(|stem| {
+                stem.char_indices().next_back().map(|(index, _)| index)
+                    == Some(last)
+            })
+        });
+        if single_consonant && silent {
+            boundaries.pop();
+        }
+    }
+    boundaries
+}
+
+/// Liang's hyphenation patterns
+#[derive(Debug, Clone, Default)]
+struct Patterns {
+    /// The letters of each pattern, and the number before each letter (and
+    /// after the last)
+    values: BTreeMap<String, Vec<u8>>,
+    /// The most letters in a pattern
+    longest: usize,
+    /// The fewest letters a split can leave at the start of the word
+    min_start: usize,
+    /// The fewest letters a split can leave at the end of the word
+    min_end: usize,
+}
+
+impl Patterns {
+    fn parse(patterns: &str) -> Self {
+        let mut parsed = Patterns {
+            min_start: 1,
+            min_end: 1,
+            ..Patterns::default()
+        };
+        for pattern in patterns
+            .lines()
+            .flat_map(|line| line.split('%').next())
+            .flat_map(str::split_whitespace)
+        {
+            let mut letters = String::new();
+            let mut values = vec![0];
+            for c in pattern.chars() {
+                match c.to_digit(10) {
+                    Some(value) => *values.last_mut().unwrap() = value as u8,
+                    None => {
+                        letters.push(c.to_lowercase().next().unwrap_or(c));
+                        values.push(0);
+                    },
+                }
+            }
+            parsed.longest = parsed.longest.max(values.len() - 1);
+            parsed.values.insert(letters, values);
+        }
+        parsed
+    }
+
+    fn boundaries(&self, word: &str) -> Vec<usize> {
+        let letters = word.char_indices().collect::<Vec<_>>();
+        let dotted = Some('.')
+            .into_iter()
+            .chain(word.chars())
+            .chain(Some('.'))
+            .collect::<Vec<_>>();
+
+        // values[n] is between dotted[n - 1] and dotted[n]
+        let mut values = vec![0; dotted.len() + 1];
+        for start in 0..dotted.len() {
+            let mut key = String::new();
+            for letter in dotted[start..].iter().take(self.longest) {
+                key.push(*letter);
+                if let Some(pattern) = self.values.get(&key) {
+                    for (value, new) in values[start..].iter_mut().zip(pattern)
+                    {
+                        *value = (*value).max(*new);
+                    }
+                }
+            }
+        }
+
+        // Only splits between letters of the word count, i.e. not before the
+        // first letter or after the last
+        (self.min_start..=letters.len().saturating_sub(self.min_end))
+            .filter(|n| values[n + 1] % 2 == 1)
+            .map(|n| letters[n].0)
+            .collect()
+    }
+}
@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+
+use crate::{syllable::boundaries_by_onsets, JoinMethod, Portmanteau};
+
+impl Portmanteau {
+    /// The join points tried after every strategy's, which keep one or both
+    /// words whole, if this generator allows them
+    ///
+    /// The words must be lowercase
+    pub(crate) fn whole_word_join_points(
+        &self,
+        left_word: &str,
+        right_word: &str,
+    ) -> Vec<(usize, usize, JoinMethod)> {
+        let mut join_points = Vec::new();
+        if self.embed_words {
+            let language = self.language();
+            // The whole left word, keeping as much of the right word as
+            // possible
+            join_points.extend(
+                boundaries_by_onsets(language, right_word).into_iter().map(
+                    |right_index| {
+                        (left_word.len(), right_index, JoinMethod::WholeWord)
+                    },
+                ),
+            );
+            // Then the whole right word, keeping as much of the left word as
+            // possible
+            join_points.extend(
+                boundaries_by_onsets(language, left_word)
+                    .into_iter()
+                    .rev()
+                    .map(|left_index| (left_index, 0, JoinMethod::WholeWord)),
+            );
+        }
+        if self.compound_fallback {
+            join_points.push((
+                left_word.len(),
+                shared_ends(left_word, right_word),
+                JoinMethod::Compound,
+            ));
+        }
+        join_points
+    }
+}
+
+/// The length in bytes of the longest start of the right word that the left
+/// word ends with, leaving at least one letter of each word unshared
+fn shared_ends(left_word: &str, right_word: &str) -> usize {
+    right_word
+        .char_indices()
+        .map(|(index, _)| index)
+        .skip(1)
+        .filter(|index| *index < left_word.len())
+        .filter(|index| left_word.ends_with(&right_word[..*index]))
+        .last()
+        .unwrap_or(0)
+}
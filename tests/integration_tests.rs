@@ -1,3 +1,5 @@
+use std::fmt::Write;
+
 use portmanteau::*;
 
 #[test]
@@ -47,6 +49,135 @@ fn output_matches_input() {
     assert_eq!(portmanteau("swords", "words"), None);
 }
 
+#[test]
+fn candidates_start_with_portmanteau() {
+    for (left_word, right_word) in [
+        ("abercrombie", "commercial"),
+        ("accidental", "businessman"),
+        ("chrome", "promise"),
+        ("swords", "words"),
+        ("tin", "can"),
+    ] {
+        let candidates = candidates(left_word, right_word);
+        assert_eq!(
+            candidates.first().map(ToString::to_string),
+            portmanteau(left_word, right_word)
+        );
+    }
+}
+
+#[test]
+fn all_candidates() {
+    assert_eq!(
+        candidates("abercrombie", "commercial"),
+        [
+            "abercial",
+            "abercrombiercial",
+            "abercrombial",
+            "abercrommercial"
+        ],
+    );
+    assert_eq!(
+        candidates("accidental", "businessman"),
+        ["accidentan", "accidessman", "accinessman", "accusinessman"],
+    );
+}
+
+#[test]
+fn blend_methods() {
+    let chromise = blend("chrome", "promise").unwrap();
+    assert_eq!(chromise.method(), JoinMethod::SharedTrio);
+    assert_eq!((chromise.left_index(), chromise.right_index()), (2, 1));
+    assert_eq!(chromise.left_fragment(), "ch");
+    assert_eq!(chromise.right_fragment(), "romise");
+
+    let liquinky = blend("liquid", "slinky").unwrap();
+    assert_eq!(liquinky.method(), JoinMethod::MatchingVowel);
+    assert_eq!(liquinky.left_fragment(), "liqu");
+    assert_eq!(liquinky.right_fragment(), "inky");
+
+    let testiclust = blend("testicle", "crust").unwrap();
+    assert_eq!(testiclust.method(), JoinMethod::FallbackVowel);
+    assert_eq!(testiclust.left_fragment(), "testicl");
+    assert_eq!(testiclust.right_fragment(), "ust");
+    assert_eq!(testiclust.to_string(), "testiclust");
+    assert_eq!(testiclust.len(), "testiclust".len());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        try_portmanteau("liquid", "tin"),
+        Err(PortmanteauError::TooShort {
+            word: "tin".to_string(),
+            min_word_size: 4,
+        })
+    );
+    assert_eq!(
+        try_portmanteau("net-flix", "chill"),
+        Err(PortmanteauError::InvalidCharacter {
+            word: "net-flix".to_string(),
+            character: '-',
+        })
+    );
+    assert_eq!(
+        try_portmanteau("liquid", "crypt"),
+        Err(PortmanteauError::NoVowels {
+            word: "crypt".to_string()
+        })
+    );
+    assert_eq!(
+        try_portmanteau("swords", "words"),
+        Err(PortmanteauError::AllRejected)
+    );
+    assert_eq!(
+        Portmanteau::builder()
+            .clear_strategies()
+            .strategy(SharedTrios)
+            .build()
+            .try_blend("liquid", "slinky"),
+        Err(PortmanteauError::NoJoinPoints)
+    );
+}
+
+#[test]
+fn many_words() {
+    assert_eq!(
+        portmanteau_many(&["basket", "butter", "cookie"]).unwrap(),
+        "baskuttookie"
+    );
+    assert_eq!(
+        portmanteau_many(&["anxiety", "clout", "constant"]).unwrap(),
+        "anxioutant"
+    );
+    assert_eq!(
+        portmanteau_many(&["ANIMAL", "CHEESE", "DANDELION"]).unwrap(),
+        "ANIMEESELION"
+    );
+    assert_eq!(
+        portmanteau_many(&["liquid", "slinky"]),
+        portmanteau("liquid", "slinky")
+    );
+    assert_eq!(portmanteau_many(&["liquid"]), None);
+    assert_eq!(portmanteau_many(&["liquid", "tin", "slinky"]), None);
+}
+
+#[test]
+fn many_words_without_shared_letters() {
+    assert_eq!(
+        portmanteau_many(&["breakfast", "lunch", "dinner"]).unwrap(),
+        "breakfunchinner"
+    );
+    assert_eq!(
+        portmanteau_many(&["liquid", "chrome", "promise", "slinky"]).unwrap(),
+        "liquomeominky"
+    );
+    assert_eq!(
+        portmanteau_many(&["Netflix", "chill", "pizza"]).unwrap(),
+        "Netflillizza"
+    );
+}
+
 #[test]
 fn check_csv() {
     csv::Reader::from_path("benches/input_pairs.csv")
@@ -67,3 +198,1720 @@ fn check_csv() {
             );
         });
 }
+
+#[test]
+fn builder_default_matches_function() {
+    let generator = Portmanteau::default();
+    assert_eq!(
+        format!("{:?}", generator),
+        format!("{:?}", PortmanteauBuilder::new().build())
+    );
+    for (left_word, right_word) in [
+        ("liquid", "slinky"),
+        ("testicle", "crust"),
+        ("chrome", "promise"),
+        ("swords", "words"),
+    ] {
+        assert_eq!(
+            generator.generate(left_word, right_word),
+            portmanteau(left_word, right_word),
+        );
+    }
+}
+
+#[test]
+fn builder_min_word_size() {
+    let generator = Portmanteau::builder().min_word_size(3).build();
+    assert_eq!(generator.generate("cat", "dog"), Some("cog".to_string()));
+    assert_eq!(portmanteau("cat", "dog"), None);
+}
+
+#[test]
+fn builder_vowels() {
+    assert_eq!(Portmanteau::default().vowels(), ['a', 'e', 'i', 'o', 'u']);
+    let generator = Portmanteau::builder().vowels("aeiouy".chars()).build();
+    assert_eq!(generator.vowels(), ['a', 'e', 'i', 'o', 'u', 'y']);
+    assert_eq!(
+        generator.generate("crypt", "coffee"),
+        Some("croffee".to_string())
+    );
+    assert_eq!(portmanteau("crypt", "coffee"), None);
+}
+
+#[test]
+fn builder_overlap_length() {
+    let generator = Portmanteau::builder().overlap_length(2).build();
+    assert_eq!(
+        generator.generate("motor", "hotel"),
+        Some("motel".to_string())
+    );
+}
+
+#[test]
+fn adaptive_overlap() {
+    let generator = Portmanteau::builder().overlap(Overlap::Adaptive).build();
+    assert_eq!(generator.overlap_length(), 2);
+    // Two letters for short words
+    assert_eq!(generator.generate("motor", "hotel").unwrap(), "motel");
+    // Three for medium words, like the default
+    assert_eq!(
+        generator.generate("chrome", "promise"),
+        portmanteau("chrome", "promise")
+    );
+    // Four for long words
+    assert_eq!(
+        generator.generate("pleasurable", "breaststroke").unwrap(),
+        "pleasuraststroke"
+    );
+}
+
+#[test]
+fn longest_overlap() {
+    let generator = Portmanteau::builder().overlap(Overlap::Longest(3)).build();
+    assert_eq!(generator.overlap(), Overlap::Longest(3));
+    assert_eq!(generator.overlap_length(), 3);
+    // The default prefers the trio nearest the end of the left word
+    assert_eq!(
+        portmanteau("airportcar", "carportable").unwrap(),
+        "airportcarportable"
+    );
+    assert_eq!(
+        generator.generate("airportcar", "carportable").unwrap(),
+        "airportable"
+    );
+    assert_eq!(
+        generator.generate("liquid", "slinky"),
+        portmanteau("liquid", "slinky")
+    );
+}
+
+/// Every run of the left word compared with every run of the right word,
+/// which is what [`SharedTrios`] should find
+#[derive(Debug)]
+struct EveryRun;
+
+impl Strategy for EveryRun {
+    fn method(&self) -> JoinMethod {
+        JoinMethod::SharedTrio
+    }
+
+    fn join_points<'a>(
+        &'a self,
+        generator: &'a Portmanteau,
+        a: &'a str,
+        b: &'a str,
+    ) -> JoinPoints<'a> {
+        let runs = |word: &'a str, length: usize| {
+            let starts = word
+                .char_indices()
+                .map(|(index, _)| index)
+                .chain(Some(word.len()))
+                .collect::<Vec<_>>();
+            starts
+                .windows(length + 1)
+                .map(|window| (window[0], &word[window[0]..window[length]]))
+                .collect::<Vec<_>>()
+        };
+        let shorter = a.chars().count().min(b.chars().count());
+        let mut join_points = Vec::new();
+        for length in generator
+            .overlap()
+            .lengths(a, b)
+            .filter(|length| *length <= shorter)
+        {
+            let b_runs = runs(b, length);
+            for (a_index, a_run) in runs(a, length).into_iter().skip(1).rev() {
+                for (b_index, b_run) in
+                    &b_runs[..b_runs.len().saturating_sub(2)]
+                {
+                    let join_point = (a_index, *b_index);
+                    if a_run == *b_run && !join_points.contains(&join_point) {
+                        join_points.push(join_point);
+                    }
+                }
+            }
+        }
+        Box::new(join_points.into_iter())
+    }
+}
+
+#[test]
+fn shared_runs_of_long_words() {
+    let words = [
+        "airportcar",
+        "carportable",
+        "chromosome",
+        "promise",
+        "abercrombiecomplexitycomplexion",
+        "complexionabercrombieconcretecomplex",
+        "antidisestablishmentarianism",
+        "establishmentarianismantidis",
+        "ÉTABLISSEMENTÉTABLISSEMENTÉTABLI",
+    ];
+    for overlap in [
+        Overlap::Fixed(3),
+        Overlap::Fixed(2),
+        Overlap::Adaptive,
+        Overlap::Longest(2),
+    ] {
+        let builder = Portmanteau::builder()
+            .overlap(overlap)
+            .reject_substrings(false)
+            .check_pronounceability(false)
+            .clear_strategies();
+        let expected = builder.clone().strategy(EveryRun).build();
+        let actual = builder.strategy(SharedTrios).build();
+        let prepared = words
+            .iter()
+            .map(|word| actual.prepare(word))
+            .collect::<Vec<_>>();
+        for (left_word, left_prepared) in words.iter().zip(&prepared) {
+            for (right_word, right_prepared) in words.iter().zip(&prepared) {
+                let join_points = |generator: &Portmanteau| {
+                    generator
+                        .candidates(left_word, right_word)
+                        .map(|blend| (blend.left_index(), blend.right_index()))
+                        .collect::<Vec<_>>()
+                };
+                assert_eq!(join_points(&actual), join_points(&expected));
+                assert_eq!(
+                    actual.blend_prepared(left_prepared, right_prepared),
+                    expected.blend(left_word, right_word)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn longest_overlap_longer_than_words() {
+    Portmanteau::builder().overlap(Overlap::Longest(5)).build();
+}
+
+#[test]
+#[should_panic]
+fn overlap_longer_than_words() {
+    Portmanteau::builder().overlap_length(5).build();
+}
+
+#[test]
+#[should_panic]
+fn margin_longer_than_words() {
+    Portmanteau::builder().vowel_search_margin(4).build();
+}
+
+#[test]
+fn strategies_default_order() {
+    let generator = Portmanteau::default();
+    let methods = generator
+        .strategies()
+        .map(Strategy::method)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        methods,
+        [
+            JoinMethod::SharedTrio,
+            JoinMethod::MatchingVowel,
+            JoinMethod::FallbackVowel
+        ]
+    );
+}
+
+#[test]
+fn strategies_reordered() {
+    let generator = Portmanteau::builder()
+        .clear_strategies()
+        .strategy(MatchingVowels)
+        .strategy(SharedTrios)
+        .build();
+    let chromise = generator.blend("chrome", "promise").unwrap();
+    assert_eq!(chromise, "chromise");
+    assert_eq!(chromise.method(), JoinMethod::MatchingVowel);
+}
+
+#[test]
+fn strategies_disabled() {
+    let generator = Portmanteau::builder()
+        .clear_strategies()
+        .strategy(SharedTrios)
+        .strategy(MatchingVowels)
+        .build();
+    assert_eq!(generator.generate("testicle", "crust"), None);
+    assert_eq!(generator.generate("liquid", "slinky").unwrap(), "liquinky");
+}
+
+#[derive(Debug)]
+struct Nonsense;
+
+impl Strategy for Nonsense {
+    fn method(&self) -> JoinMethod {
+        JoinMethod::Custom("nonsense")
+    }
+
+    fn join_points<'a>(
+        &'a self,
+        _: &'a Portmanteau,
+        _: &'a str,
+        _: &'a str,
+    ) -> JoinPoints<'a> {
+        Box::new(vec![(100, 0), (0, 100), (2, 1)].into_iter())
+    }
+}
+
+#[test]
+fn out_of_bounds_join_points_skipped() {
+    let generator = Portmanteau::builder()
+        .clear_strategies()
+        .strategy(Nonsense)
+        .build();
+    let blend = generator.blend("chrome", "promise").unwrap();
+    assert_eq!(blend, "chromise");
+    assert_eq!(blend.method(), JoinMethod::Custom("nonsense"));
+}
+
+#[test]
+#[should_panic]
+fn no_strategies() {
+    Portmanteau::builder().clear_strategies().build();
+}
+
+#[test]
+fn best_blend_scores_highest() {
+    assert_eq!(best_blend("abounds", "communist").unwrap(), "abounist");
+    assert_eq!(best_blend("abercrombie", "bidet").unwrap(), "abercrombidet");
+    assert_eq!(best_blend("chrome", "promise").unwrap(), "chromise");
+    assert_eq!(best_blend("NETFLIX", "CHILL").unwrap(), "NETFLILL");
+    assert_eq!(best_blend("tin", "can"), None);
+}
+
+#[test]
+fn best_is_a_candidate() {
+    for (left, right) in [("abounds", "amputation"), ("basket", "butter")] {
+        let best = best_blend(left, right).unwrap();
+        assert!(candidates(left, right).contains(&best));
+    }
+}
+
+#[test]
+fn scores_in_range() {
+    let generator = Portmanteau::default();
+    for blend in candidates("acceptable", "captain") {
+        let score = generator.score(&blend);
+        assert!((0.0..=1.0).contains(&score), "{} scored {}", blend, score);
+    }
+}
+
+#[test]
+fn scorer_weights() {
+    let longest = Portmanteau::builder()
+        .scorer(WeightedScorer {
+            overlap: 0.0,
+            retained: 1.0,
+            pronounceability: 0.0,
+            length: 0.0,
+            whole_word: 0.0,
+        })
+        .build();
+    assert_eq!(
+        longest.best_blend("acceptable", "captain").unwrap(),
+        "acceptablaptain"
+    );
+
+    let nothing = Portmanteau::builder()
+        .scorer(WeightedScorer {
+            overlap: 0.0,
+            retained: 0.0,
+            pronounceability: 0.0,
+            length: 0.0,
+            whole_word: 0.0,
+        })
+        .build();
+    let blend = nothing.blend("abercrombie", "complex").unwrap();
+    assert_eq!(nothing.score(&blend), 0.0);
+    // Ties go to the first candidate
+    assert_eq!(nothing.best_blend("abercrombie", "complex"), Some(blend));
+}
+
+#[derive(Debug)]
+struct Longest;
+
+impl Scorer for Longest {
+    fn score(&self, _: &Portmanteau, blend: &Blend) -> f64 {
+        blend.len() as f64
+    }
+}
+
+#[test]
+fn custom_scorer() {
+    let generator = Portmanteau::builder().scorer(Longest).build();
+    let blend = generator.best_blend("acceptable", "captain").unwrap();
+    assert_eq!(blend, "acceptablaptain");
+    assert_eq!(generator.score(&blend), 15.0);
+    // Only the best blend is affected
+    assert_eq!(
+        generator.blend("acceptable", "captain").unwrap(),
+        "acceptain"
+    );
+}
+
+#[test]
+fn accents() {
+    assert_eq!(portmanteau("señorita", "piñata").unwrap(), "señoritata");
+    assert_eq!(
+        try_portmanteau("crème", "brûlée"),
+        Err(PortmanteauError::NoVowels {
+            word: "brûlée".to_string()
+        })
+    );
+    assert_eq!(
+        Portmanteau::builder()
+            .language(LanguageProfile::french())
+            .build()
+            .generate("crème", "brûlée")
+            .unwrap(),
+        "crèmûlée"
+    );
+}
+
+#[test]
+fn other_alphabets() {
+    assert_eq!(
+        Portmanteau::builder()
+            .language(LanguageProfile::russian())
+            .build()
+            .generate("молоко", "корова")
+            .unwrap(),
+        "молокорова"
+    );
+    assert_eq!(
+        Portmanteau::builder()
+            .language(LanguageProfile::greek())
+            .build()
+            .generate("θάλασσα", "ουρανός")
+            .unwrap(),
+        "θάλασσανός"
+    );
+}
+
+#[test]
+fn more_vowel_sets() {
+    let generator =
+        |language| Portmanteau::builder().language(language).build();
+    let scandinavian = generator(LanguageProfile::scandinavian());
+    assert_eq!(scandinavian.generate("kålrot", "råtten").unwrap(), "kåtten");
+    assert_eq!(scandinavian.generate("hygge", "lykke").unwrap(), "hykke");
+    assert_eq!(portmanteau("hygge", "lykke"), None);
+    assert_eq!(
+        generator(LanguageProfile::ukrainian())
+            .generate("мрія", "стіна")
+            .unwrap(),
+        "мріна"
+    );
+    let latin = generator(LanguageProfile::latin_extended());
+    assert_eq!(latin.generate("ząbek", "mąka").unwrap(), "ząka");
+    assert_eq!(latin.generate("księżyc", "ręka").unwrap(), "księka");
+}
+
+#[test]
+fn letters_not_bytes() {
+    // 3 letters, but 6 bytes
+    assert_eq!(
+        try_portmanteau("ёжи", "ёлка"),
+        Err(PortmanteauError::TooShort {
+            word: "ёжи".to_string(),
+            min_word_size: 4
+        })
+    );
+    let blend = Portmanteau::builder()
+        .language(LanguageProfile::german())
+        .build()
+        .blend("straße", "größe")
+        .unwrap();
+    assert_eq!(blend, "straßöße");
+    assert_eq!(blend.left_fragment(), "straß");
+}
+
+#[test]
+fn unsayable_rejected() {
+    let (on, off) = (
+        Portmanteau::builder()
+            .overlap_length(1)
+            .clear_strategies()
+            .strategy(SharedTrios)
+            .check_pronounceability(true)
+            .build(),
+        Portmanteau::builder()
+            .overlap_length(1)
+            .clear_strategies()
+            .strategy(SharedTrios)
+            .check_pronounceability(false)
+            .build(),
+    );
+    assert!(on.checks_pronounceability());
+    assert!(!off.checks_pronounceability());
+    assert_eq!(off.generate("abstract", "burger").unwrap(), "abstrger");
+    assert_eq!(on.generate("abstract", "burger").unwrap(), "aburger");
+    assert_eq!(off.generate("aggressive", "cards").unwrap(), "aggrds");
+    assert_eq!(on.generate("aggressive", "cards"), None);
+}
+
+#[test]
+fn clusters_from_input_allowed() {
+    // "nds" is the end of "abounds"
+    let on = Portmanteau::builder()
+        .overlap_length(1)
+        .clear_strategies()
+        .strategy(SharedTrios)
+        .check_pronounceability(true)
+        .build();
+    assert_eq!(on.generate("abounds", "aussie").unwrap(), "aboundsie");
+}
+
+#[test]
+fn coda_then_onset() {
+    let on = Portmanteau::builder()
+        .overlap_length(1)
+        .clear_strategies()
+        .strategy(SharedTrios)
+        .check_pronounceability(true)
+        .build();
+    // "m" + "bl"
+    assert_eq!(
+        on.generate("abercrombie", "blame").unwrap(),
+        "abercromblame"
+    );
+    // "ct" + "r"
+    assert_eq!(
+        on.generate("abstract", "destructive").unwrap(),
+        "abstractructive"
+    );
+}
+
+#[test]
+fn unpronounceable_error() {
+    assert_eq!(
+        Portmanteau::builder()
+            .overlap_length(1)
+            .clear_strategies()
+            .strategy(SharedTrios)
+            .check_pronounceability(true)
+            .build()
+            .try_blend("aggressive", "cards"),
+        Err(PortmanteauError::Unpronounceable)
+    );
+    assert_eq!(
+        PortmanteauError::Unpronounceable.to_string(),
+        "every portmanteau found was unpronounceable"
+    );
+}
+
+#[test]
+fn error_either_order() {
+    let generator = Portmanteau::builder()
+        .either_order(true)
+        .output_length(..4)
+        .build();
+    assert_eq!(
+        generator.try_blend("liquid", "slinky"),
+        Err(PortmanteauError::OutsideLimits)
+    );
+    assert_eq!(
+        generator.try_blend("rhythm", "crypt"),
+        Err(PortmanteauError::NoVowels {
+            word: "rhythm".to_string()
+        })
+    );
+}
+
+#[test]
+fn pronounceability_default_off() {
+    assert!(!Portmanteau::default().checks_pronounceability());
+    assert_eq!(portmanteau("sdfghjk", "qwrdfgvbnm").unwrap(), "sdfgvbnm");
+}
+
+#[test]
+fn vowel_runs_across_join_rejected() {
+    // Joined on the vowels the default strategies find
+    let checked = Portmanteau::builder().check_pronounceability(true).build();
+    assert_eq!(portmanteau("between", "through").unwrap(), "betweough");
+    assert_eq!(
+        checked.try_blend("between", "through"),
+        Err(PortmanteauError::Unpronounceable)
+    );
+    assert_eq!(portmanteau("keep", "seed").unwrap(), "keeed");
+    assert_eq!(checked.generate("keep", "seed"), None);
+    // Two vowels are fine, as are longer runs from the input words
+    assert_eq!(checked.generate("their", "must").unwrap(), "theust");
+    assert_eq!(checked.generate("value", "queue").unwrap(), "valueue");
+}
+
+#[test]
+fn builder_vowels_keep_language() {
+    let generator = Portmanteau::builder()
+        .language(LanguageProfile::german())
+        .vowels(['a', 'e', 'i'])
+        .build();
+    assert_eq!(
+        generator.language().vowels().collect::<Vec<_>>(),
+        ["a", "e", "i"]
+    );
+    assert_eq!(
+        generator.language().onsets().collect::<Vec<_>>(),
+        LanguageProfile::german().onsets().collect::<Vec<_>>()
+    );
+    assert!(generator.language().semivowels().eq(["y"]));
+}
+
+#[test]
+fn syllable_split() {
+    let syllables = Syllables::new();
+    let english = LanguageProfile::english();
+    assert_eq!(syllables.split(&english, "dinosaur"), ["di", "no", "saur"]);
+    assert_eq!(syllables.split(&english, "algebra"), ["al", "ge", "bra"]);
+    assert_eq!(
+        syllables.split(&english, "Basketball"),
+        ["Ba", "sket", "ball"]
+    );
+    assert_eq!(syllables.split(&english, "strengths"), ["strengths"]);
+}
+
+#[test]
+fn silent_endings() {
+    let syllables = Syllables::new();
+    let english = LanguageProfile::english();
+    assert_eq!(
+        syllables.split(&english, "destructive"),
+        ["de", "struc", "tive"]
+    );
+    // Only after a single consonant
+    assert_eq!(syllables.split(&english, "beetle"), ["beet", "le"]);
+    assert_eq!(
+        syllables
+            .split(&english.with_silent_endings(Vec::<&str>::new()), "cave"),
+        ["ca", "ve"]
+    );
+}
+
+#[test]
+fn syllables_other_languages() {
+    let syllables = Syllables::new();
+    assert_eq!(
+        syllables.split(&LanguageProfile::greek(), "αλφάβητο"),
+        ["αλ", "φά", "βη", "το"]
+    );
+    assert_eq!(
+        syllables.split(&LanguageProfile::russian(), "собака"),
+        ["со", "ба", "ка"]
+    );
+    assert_eq!(
+        syllables.split(&LanguageProfile::dutch(), "tijdschrift"),
+        ["tijd", "schrift"]
+    );
+}
+
+#[test]
+fn syllables_strategy() {
+    let generator = Portmanteau::builder()
+        .clear_strategies()
+        .strategy(Syllables::new())
+        .build();
+    let blend = generator.blend("chocolate", "avocado").unwrap();
+    assert_eq!(blend, "chococado");
+    assert_eq!(blend.method(), JoinMethod::SyllableBoundary);
+    assert_eq!(
+        generator.generate("spanish", "english").unwrap(),
+        "spaglish"
+    );
+    assert_eq!(
+        generator.generate("Banana", "DINOSAUR").unwrap(),
+        "Banasaur"
+    );
+    // One syllable words can't be joined
+    assert_eq!(generator.generate("testicle", "crust"), None);
+}
+
+#[test]
+fn syllables_after_defaults() {
+    let generator = Portmanteau::builder().strategy(Syllables::new()).build();
+    assert_eq!(generator.generate("liquid", "slinky").unwrap(), "liquinky");
+}
+
+#[test]
+fn syllable_patterns() {
+    let syllables = Syllables::from_patterns(
+        "% Comments are ignored
+         n1n s1s
+         t1t",
+    );
+    let english = LanguageProfile::english();
+    assert_eq!(
+        syllables.split(&english, "Tennessee"),
+        ["Ten", "nes", "see"]
+    );
+    assert_eq!(syllables.split(&english, "banana"), ["banana"]);
+    assert_eq!(
+        Portmanteau::builder()
+            .clear_strategies()
+            .strategy(syllables)
+            .build()
+            .generate("tennessee", "kitten")
+            .unwrap(),
+        "tennesten"
+    );
+}
+
+#[test]
+fn higher_pattern_wins() {
+    let syllables = Syllables::from_patterns("a1n 2nan");
+    let english = LanguageProfile::english();
+    assert_eq!(syllables.split(&english, "banana"), ["bana", "na"]);
+    let syllables = Syllables::from_patterns(".ba1 4n");
+    assert_eq!(syllables.split(&english, "banana"), ["banana"]);
+}
+
+#[test]
+fn english_patterns() {
+    let syllables = Syllables::english_patterns();
+    let english = LanguageProfile::english();
+    assert_eq!(syllables.split(&english, "Computer"), ["Com", "puter"]);
+    // Not split less than three letters from the end
+    assert_eq!(syllables.split(&english, "elephant"), ["ele", "phant"]);
+    assert_eq!(
+        Portmanteau::builder()
+            .clear_strategies()
+            .strategy(syllables)
+            .build()
+            .generate("chocolate", "elephant")
+            .unwrap(),
+        "chocophant"
+    );
+}
+
+#[test]
+fn transcribe() {
+    let english = Phonemes::english();
+    assert_eq!(english.transcribe("rock"), ["R", "AA", "K"]);
+    assert_eq!(english.transcribe("karate"), ["K", "AA", "R", "EY", "T"]);
+    assert_eq!(english.transcribe("Ceiling"), ["S", "EY", "L", "IH", "NG"]);
+    assert_eq!(
+        english.transcribe("fiction"),
+        ["F", "IH", "K", "SH", "AH", "N"]
+    );
+    assert_eq!(english.transcribe("taxi"), ["T", "AE", "K", "S", "IH"]);
+    assert_eq!(english.transcribe("thumb"), ["TH", "AH", "M"]);
+}
+
+#[test]
+fn spelled_differently() {
+    let generator = Portmanteau::builder()
+        .clear_strategies()
+        .strategy(Phonemes::english())
+        .build();
+    let blend = generator.blend("rock", "karate").unwrap();
+    assert_eq!(blend, "rokarate");
+    assert_eq!(blend.method(), JoinMethod::SharedSound);
+    assert_eq!(
+        generator.generate("juice", "seltzer").unwrap(),
+        "juiseltzer"
+    );
+    assert_eq!(
+        generator.generate("Graph", "fiction").unwrap(),
+        "Grafiction"
+    );
+    // The letter based strategies join on the vowel instead
+    assert_eq!(portmanteau("rock", "karate").unwrap(), "rarate");
+}
+
+#[test]
+fn longest_shared_sound() {
+    // "ROM" rather than the later "S"
+    assert_eq!(
+        Portmanteau::builder()
+            .clear_strategies()
+            .strategy(Phonemes::english())
+            .build()
+            .generate("chrome", "promise")
+            .unwrap(),
+        "chromise"
+    );
+}
+
+#[test]
+fn no_shared_sounds() {
+    assert_eq!(
+        Portmanteau::builder()
+            .clear_strategies()
+            .strategy(Phonemes::english())
+            .build()
+            .generate("breakfast", "lunch"),
+        None
+    );
+}
+
+#[test]
+fn similar_vowel_sounds() {
+    let generator = Portmanteau::builder()
+        .min_word_size(3)
+        .clear_strategies()
+        .strategy(Phonemes::english())
+        .build();
+    // The "o"s are said differently, but both at the back of the mouth
+    let blend = generator.blend("smoke", "fog").unwrap();
+    assert_eq!(blend, "smog");
+    assert_eq!(blend.method(), JoinMethod::SharedSound);
+    // A shared run is still preferred
+    assert_eq!(generator.generate("chrome", "promise").unwrap(), "chromise");
+}
+
+#[test]
+fn english_semivowels() {
+    let english = Portmanteau::builder()
+        .language(LanguageProfile::english().with_semivowels(true))
+        .build();
+    assert_eq!(english.generate("rhythm", "music").unwrap(), "rhusic");
+    assert_eq!(english.generate("system", "crypt").unwrap(), "sypt");
+    assert_eq!(portmanteau("rhythm", "music"), None);
+}
+
+#[test]
+fn semivowel_at_start_is_consonant() {
+    let english = Portmanteau::builder()
+        .language(LanguageProfile::english().with_semivowels(true))
+        .build();
+    let blend = english.blend("happy", "yellow").unwrap();
+    assert_eq!(blend, "happellow");
+    assert_eq!(blend.right_index(), 1);
+}
+
+#[test]
+fn digraph_vowels() {
+    let blend = Portmanteau::builder()
+        .language(LanguageProfile::dutch())
+        .build()
+        .blend("schrijven", "tijdschrift")
+        .unwrap();
+    assert_eq!(blend.left_fragment(), "schr");
+    assert_eq!(blend.right_fragment(), "ijdschrift");
+    assert_eq!(blend.method(), JoinMethod::MatchingVowel);
+
+    // "ij" isn't a single letter
+    let generator = Portmanteau::builder()
+        .language(LanguageProfile::dutch().with_semivowels(true))
+        .build();
+    assert_eq!(
+        generator.vowels(),
+        ['a', 'e', 'i', 'o', 'u', 'é', 'ë', 'ï', 'ö', 'ü', 'y']
+    );
+}
+
+#[test]
+fn accented_vowels() {
+    assert_eq!(
+        Portmanteau::builder()
+            .language(LanguageProfile::german())
+            .build()
+            .generate("schmetterling", "frühling")
+            .unwrap(),
+        "schmetterlühling"
+    );
+    assert_eq!(
+        Portmanteau::builder()
+            .language(LanguageProfile::french())
+            .build()
+            .generate("crème", "brûlée")
+            .unwrap(),
+        "crèmûlée"
+    );
+    assert_eq!(
+        Portmanteau::builder()
+            .language(LanguageProfile::russian())
+            .build()
+            .generate("молоко", "корова")
+            .unwrap(),
+        "молокорова"
+    );
+}
+
+#[test]
+fn custom_language() {
+    let language =
+        LanguageProfile::new(vec!["a", "o"], vec!["w"]).with_semivowels(true);
+    assert_eq!(language.vowels().collect::<Vec<_>>(), ["a", "o"]);
+    assert_eq!(language.semivowels().collect::<Vec<_>>(), ["w"]);
+    assert!(language.uses_semivowels());
+    assert_eq!(
+        Portmanteau::builder()
+            .language(language.clone())
+            .build()
+            .language(),
+        &language
+    );
+}
+
+#[test]
+fn title_case() {
+    let blend = blend("Brad", "Angelina").unwrap();
+    assert_eq!(blend.casing(), Casing::Title);
+    assert_eq!(blend.right_fragment(), "Angelina");
+    assert_eq!(blend.to_string(), "Brangelina");
+    assert_eq!(blend, "Brangelina");
+    assert_eq!(portmanteau("Netflix", "Chill").unwrap(), "Netflill");
+}
+
+#[test]
+fn upper_case() {
+    assert_eq!(portmanteau("NETFLIX", "CHILL").unwrap(), "NETFLILL");
+    // Only one word in capitals
+    assert_eq!(portmanteau("NETFLIX", "chill").unwrap(), "Netflill");
+}
+
+#[test]
+fn lower_case() {
+    assert_eq!(portmanteau("liquid", "Slinky").unwrap(), "liquinky");
+}
+
+#[test]
+fn mixed_case() {
+    let blend = blend("iPhone", "Android").unwrap();
+    assert_eq!(blend.casing(), Casing::Mixed);
+    assert_eq!(blend, "iPhoid");
+    assert_eq!(portmanteau("McDonald", "Donut").unwrap(), "McDonut");
+    // Names with a capital after a hyphen are still in title case
+    let names = Portmanteau::builder().for_names().build();
+    let blend = names.blend("Mary-Kate", "Ashley").unwrap();
+    assert_eq!(blend.casing(), Casing::Title);
+}
+
+#[test]
+fn case_insensitive() {
+    for (left_word, right_word) in [
+        ("chrome", "promise"),
+        ("liquid", "slinky"),
+        ("testicle", "crust"),
+    ] {
+        let lowercase = blend(left_word, right_word).unwrap();
+        let uppercase =
+            blend(&left_word.to_uppercase(), &right_word.to_uppercase())
+                .map(|blend| (blend.left_index(), blend.right_index()));
+        assert_eq!(
+            Some((lowercase.left_index(), lowercase.right_index())),
+            uppercase
+        );
+    }
+    assert_eq!(
+        try_portmanteau("Swords", "WORDS"),
+        Err(PortmanteauError::AllRejected)
+    );
+}
+
+#[test]
+fn multibyte_casing() {
+    let generator = Portmanteau::builder()
+        .language(LanguageProfile::german())
+        .build();
+    assert_eq!(generator.blend("STRASSE", "GRÖSSE").unwrap(), "STRASSÖSSE");
+    let blend = generator.blend("Straße", "GRÖßE").unwrap();
+    assert_eq!(blend.casing(), Casing::Title);
+    assert_eq!(blend.to_string(), "Straßöße");
+    assert_eq!(blend.len(), blend.to_string().len());
+}
+
+const PREPARED_WORDS: [&str; 14] = [
+    "liquid",
+    "slinky",
+    "Chrome",
+    "PROMISE",
+    "acceptable",
+    "captain",
+    "motor",
+    "hotel",
+    "Straße",
+    "größe",
+    "sdfghjk",
+    "tin",
+    "symbols!",
+    "banana",
+];
+
+fn assert_same_as_unprepared(generator: &Portmanteau) {
+    let prepared = PREPARED_WORDS
+        .iter()
+        .map(|word| generator.prepare(word))
+        .collect::<Vec<_>>();
+    for left_word in &prepared {
+        for right_word in &prepared {
+            assert_eq!(
+                generator.blend_prepared(left_word, right_word),
+                generator.blend(left_word.word(), right_word.word()),
+                "{} + {}",
+                left_word.word(),
+                right_word.word()
+            );
+        }
+    }
+}
+
+#[test]
+fn prepared_default() {
+    assert_same_as_unprepared(&Portmanteau::default());
+}
+
+#[test]
+fn prepared_overlaps() {
+    for overlap in [Overlap::Fixed(2), Overlap::Adaptive, Overlap::Longest(2)] {
+        assert_same_as_unprepared(
+            &Portmanteau::builder().overlap(overlap).build(),
+        );
+    }
+}
+
+#[test]
+fn prepared_other_strategies() {
+    assert_same_as_unprepared(
+        &Portmanteau::builder()
+            .clear_strategies()
+            .strategy(Syllables::new())
+            .strategy(FallbackVowels)
+            .build(),
+    );
+}
+
+#[test]
+fn prepared_validation() {
+    let generator = Portmanteau::default();
+    assert!(generator.prepare("Chrome").is_valid());
+    assert_eq!(generator.prepare("Chrome").lowercase(), "chrome");
+    assert!(!generator.prepare("tin").is_valid());
+    assert!(!generator.prepare("symbols!").is_valid());
+}
+
+#[test]
+fn blend_is_first_candidate() {
+    let generators = [
+        Portmanteau::default(),
+        Portmanteau::builder().overlap(Overlap::Longest(2)).build(),
+        Portmanteau::builder()
+            .strategy(Syllables::new())
+            .compound_fallback(true)
+            .build(),
+        Portmanteau::builder()
+            .lexicon(WordList::new(["liquinky", "chromise", "acceptain"]))
+            .existing_words(ExistingWords::Last)
+            .build(),
+    ];
+    for generator in &generators {
+        for left_word in PREPARED_WORDS {
+            for right_word in PREPARED_WORDS {
+                assert_eq!(
+                    generator.blend(left_word, right_word),
+                    generator.candidates(left_word, right_word).next(),
+                    "{} + {}",
+                    left_word,
+                    right_word
+                );
+            }
+        }
+    }
+}
+
+const PARTNER_WORDS: [&str; 12] = [
+    "promise",
+    "slinky",
+    "hotel",
+    "captain",
+    "chromosome",
+    "abercrombie",
+    "comrade",
+    "brown",
+    "carrot",
+    "Chrome",
+    "tin",
+    "promise",
+];
+
+#[test]
+fn partners_same_as_blend() {
+    let index = PartnerIndex::new(&Portmanteau::default(), PARTNER_WORDS);
+    let generator = index.generator();
+    for word in ["chrome", "liquid", "acceptable", "Netflix"] {
+        for partner in index.right_partners(word, 5) {
+            assert_eq!(word, partner.left_word());
+            assert_eq!(
+                Some(partner),
+                generator.blend(word, partner.right_word())
+            );
+        }
+        for partner in index.left_partners(word, 5) {
+            assert_eq!(word, partner.right_word());
+            assert_eq!(
+                Some(partner),
+                generator.blend(partner.left_word(), word)
+            );
+        }
+    }
+}
+
+#[test]
+fn partners_best_first() {
+    let index = PartnerIndex::new(&Portmanteau::default(), PARTNER_WORDS);
+    let partners = index.right_partners("chrome", 3);
+    assert_eq!(partners, ["chromise", "chrombie", "chromrade"]);
+    let scores = partners
+        .iter()
+        .map(|blend| index.generator().score(blend))
+        .collect::<Vec<_>>();
+    assert!(scores.windows(2).all(|pair| pair[0] >= pair[1]));
+
+    assert_eq!(index.left_partners("chrome", 1), ["abercrome"]);
+}
+
+#[test]
+fn partners_tiered() {
+    let index = PartnerIndex::new(&Portmanteau::default(), PARTNER_WORDS);
+    let partners = index.right_partners("abercrombie", 3);
+    // "Chrome" only shares a vowel, so it comes after the words sharing a
+    // run of letters even though it scores higher
+    assert_eq!(partners, ["abercromise", "abercromosome", "abercrome"]);
+    let score = |blend| index.generator().score(blend);
+    assert!(score(&partners[2]) > score(&partners[1]));
+}
+
+#[test]
+fn partners_count() {
+    let index = PartnerIndex::new(&Portmanteau::default(), PARTNER_WORDS);
+    assert_eq!(index.len(), 10);
+    assert!(index.right_partners("chrome", 0).is_empty());
+    assert_eq!(index.right_partners("chrome", 2).len(), 2);
+    assert!(index.right_partners("chrome", 100).len() < index.len());
+}
+
+#[test]
+fn partners_vowels_only() {
+    let index = PartnerIndex::new(
+        &Portmanteau::default(),
+        ["slinky", "hotel", "liquid"],
+    );
+    assert_eq!(index.right_partners("liquid", 3), ["liquinky"]);
+}
+
+#[test]
+fn partners_invalid() {
+    let index = PartnerIndex::new(&Portmanteau::default(), PARTNER_WORDS);
+    assert!(index.right_partners("tin", 5).is_empty());
+    assert!(index.left_partners("symbols!", 5).is_empty());
+    assert!(PartnerIndex::new(&Portmanteau::default(), []).is_empty());
+}
+
+#[test]
+fn word_list() {
+    let words = WordList::new(["Crutches", " itches ", "", "crutches"]);
+    assert_eq!(words.len(), 2);
+    assert!(words.contains("crutches"));
+    assert!(words.contains("itches"));
+    assert!(!words.contains("crutch"));
+    assert!(!words.contains("Crutches"));
+    assert!(WordList::default().is_empty());
+    assert!(!WordList::default().contains(""));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn word_list_from_file() {
+    let path = std::env::temp_dir().join("portmanteau_word_list.txt");
+    std::fs::write(&path, "crutches\nItches\n\ncrutch\n").unwrap();
+    let words = WordList::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(words, WordList::new(["crutch", "crutches", "itches"]));
+
+    assert!(WordList::from_file(&path).is_err());
+}
+
+#[test]
+fn existing_words_rejected() {
+    assert_eq!(portmanteau("crutch", "itches").unwrap(), "crutches");
+    let generator = Portmanteau::builder()
+        .lexicon(WordList::new(["crutches"]))
+        .existing_words(ExistingWords::Reject)
+        .build();
+    assert!(generator.lexicon().is_some());
+    assert_eq!(generator.generate("crutch", "itches").unwrap(), "critches");
+    assert_eq!(generator.generate("CRUTCH", "ITCHES").unwrap(), "CRITCHES");
+    assert!(generator
+        .candidates("crutch", "itches")
+        .all(|blend| !generator.is_existing_word(&blend)));
+
+    let generator = Portmanteau::builder()
+        .lexicon(WordList::new(["crutches", "critches"]))
+        .existing_words(ExistingWords::Reject)
+        .build();
+    assert_eq!(generator.generate("crutch", "itches"), None);
+    assert_eq!(
+        generator.try_blend("crutch", "itches"),
+        Err(PortmanteauError::AllExistingWords)
+    );
+}
+
+#[test]
+fn existing_words_last() {
+    let generator = Portmanteau::builder()
+        .lexicon(WordList::new(["crutches"]))
+        .existing_words(ExistingWords::Last)
+        .build();
+    assert_eq!(generator.generate("crutch", "itches").unwrap(), "critches");
+    assert_eq!(
+        generator.best_blend("crutch", "itches").unwrap(),
+        "critches"
+    );
+    assert_eq!(
+        generator.candidates("crutch", "itches").collect::<Vec<_>>(),
+        ["critches", "crutches"]
+    );
+
+    // Only used if there's nothing else
+    let generator = Portmanteau::builder()
+        .lexicon(WordList::new(["crutches", "critches"]))
+        .existing_words(ExistingWords::Last)
+        .build();
+    assert_eq!(generator.generate("crutch", "itches").unwrap(), "crutches");
+    assert_eq!(
+        generator.best_blend("crutch", "itches"),
+        best_blend("crutch", "itches")
+    );
+}
+
+#[test]
+fn existing_words_prepared() {
+    for existing_words in [ExistingWords::Reject, ExistingWords::Last] {
+        let generator = Portmanteau::builder()
+            .lexicon(WordList::new(["crutches"]))
+            .existing_words(existing_words)
+            .build();
+        let left_word = generator.prepare("crutch");
+        let right_word = generator.prepare("itches");
+        assert_eq!(
+            generator.blend_prepared(&left_word, &right_word),
+            generator.blend("crutch", "itches")
+        );
+    }
+}
+
+const DECOMPOSE_WORDS: [&str; 12] = [
+    "liquid",
+    "slinky",
+    "chrome",
+    "promise",
+    "acceptable",
+    "captain",
+    "fluffy",
+    "turtle",
+    "crutch",
+    "itches",
+    "hotel",
+    "motor",
+];
+
+#[test]
+fn decompose_round_trip() {
+    let lexicon = WordList::new(DECOMPOSE_WORDS);
+    for left_word in DECOMPOSE_WORDS {
+        for right_word in DECOMPOSE_WORDS {
+            let Some(blend) = blend(left_word, right_word) else {
+                continue;
+            };
+            let sources = decompose(&blend.to_string(), &lexicon);
+            assert!(
+                sources.iter().any(|source| {
+                    source.left_word() == left_word
+                        && source.right_word() == right_word
+                }),
+                "{} + {} = {}",
+                left_word,
+                right_word,
+                blend
+            );
+            assert!(sources.iter().all(|source| *source == blend));
+        }
+    }
+}
+
+#[test]
+fn decompose_generator_settings() {
+    let lexicon = WordList::new(DECOMPOSE_WORDS);
+    assert!(decompose("motel", &lexicon).is_empty());
+    let generator = Portmanteau::builder().overlap(Overlap::Adaptive).build();
+    let sources = generator.decompose("MOTEL", &lexicon);
+    assert_eq!(sources, ["motel"]);
+    assert_eq!(sources[0].left_word(), "motor");
+    assert_eq!(sources[0].right_word(), "hotel");
+}
+
+#[test]
+fn decompose_either_order() {
+    let lexicon = WordList::new(["liquid", "slinky"]);
+    assert_eq!(decompose("liquinky", &lexicon), ["liquinky"]);
+    assert_eq!(decompose("sliquid", &lexicon), ["sliquid"]);
+
+    let generator = Portmanteau::builder().either_order(true).build();
+    assert!(generator.decompose("liquinky", &lexicon).is_empty());
+    let sources = generator.decompose("sliquid", &lexicon);
+    assert_eq!(sources, ["sliquid"]);
+    assert_eq!(sources[0].left_word(), "slinky");
+    assert_eq!(sources[0].right_word(), "liquid");
+}
+
+#[test]
+fn decompose_not_a_portmanteau() {
+    let lexicon = WordList::new(DECOMPOSE_WORDS);
+    assert!(decompose("liquid", &lexicon).is_empty());
+    assert!(decompose("", &lexicon).is_empty());
+    assert!(decompose("banana", &lexicon).is_empty());
+}
+
+#[test]
+fn decompose_unlisted_lexicon() {
+    #[derive(Debug)]
+    struct Everything;
+
+    impl Lexicon for Everything {
+        fn contains(&self, _: &str) -> bool {
+            true
+        }
+    }
+
+    assert!(decompose("liquinky", &Everything).is_empty());
+}
+
+#[test]
+fn first_names() {
+    let generator = Portmanteau::builder().for_names().build();
+    assert_eq!(generator.names(), Some(NameToken::First));
+    assert_eq!(generator.min_word_size(), 3);
+    assert_eq!(
+        generator.generate("Ben Affleck", "Jennifer Lopez").unwrap(),
+        "Bennifer"
+    );
+    let blend = generator.blend("Brad Pitt", "Angelina Jolie").unwrap();
+    assert_eq!(blend, "Brangelina");
+    assert_eq!(blend.left_word(), "Brad");
+    assert_eq!(blend.right_word(), "Angelina");
+    assert_eq!(blend.method(), JoinMethod::MatchingVowel);
+}
+
+#[test]
+fn last_names() {
+    let generator = Portmanteau::builder()
+        .for_names()
+        .names(NameToken::Last)
+        .build();
+    let blend = generator.blend("Brad Pitt", "Angelina Jolie").unwrap();
+    assert_eq!(blend, "Pie");
+    assert_eq!(blend.right_word(), "Jolie");
+}
+
+#[test]
+fn short_names() {
+    assert_eq!(
+        Portmanteau::builder()
+            .for_names()
+            .build()
+            .generate("Jay", "Beyonce")
+            .unwrap(),
+        "Jeyonce"
+    );
+    assert_eq!(portmanteau("Jay", "Beyonce"), None);
+    assert_eq!(
+        Portmanteau::builder()
+            .for_names()
+            .build()
+            .try_blend("Al Gore", "Oprah"),
+        Err(PortmanteauError::TooShort {
+            word: "Al".to_string(),
+            min_word_size: 3,
+        })
+    );
+}
+
+#[test]
+fn very_short_names() {
+    let generator = Portmanteau::builder().for_names().build();
+    assert!(generator.embeds_words());
+    assert!(generator.falls_back_to_compounds());
+    let blend = generator.blend("Kim Kardashian", "Kanye West").unwrap();
+    assert_eq!(blend, "Kimye");
+    assert_eq!(blend.method(), JoinMethod::WholeWord);
+    let blend = generator.blend("Ben", "Jen").unwrap();
+    assert_eq!(blend, "Benjen");
+    assert_eq!(blend.method(), JoinMethod::Compound);
+}
+
+#[test]
+fn hyphens_and_apostrophes_in_names() {
+    let generator = Portmanteau::builder().for_names().build();
+    assert_eq!(
+        generator
+            .generate("Mary-Kate Olsen", "Ashley Olsen")
+            .unwrap(),
+        "Mary-Kashley"
+    );
+    assert_eq!(
+        Portmanteau::builder()
+            .for_names()
+            .names(NameToken::Last)
+            .build()
+            .generate("Conan O'Brien", "Andy Richter")
+            .unwrap(),
+        "O'Brier"
+    );
+    // Only between letters
+    assert_eq!(
+        generator.try_blend("-Ann", "Bob"),
+        Err(PortmanteauError::InvalidCharacter {
+            word: "-Ann".to_string(),
+            character: '-',
+        })
+    );
+    // And only in names
+    assert_eq!(
+        try_portmanteau("mary-kate", "ashley"),
+        Err(PortmanteauError::InvalidCharacter {
+            word: "mary-kate".to_string(),
+            character: '-',
+        })
+    );
+}
+
+#[test]
+fn capitalised_names() {
+    let generator = Portmanteau::builder().for_names().build();
+    assert_eq!(
+        generator.generate("brad", "angelina").unwrap(),
+        "Brangelina"
+    );
+    assert_eq!(
+        generator.generate("BRAD", "ANGELINA").unwrap(),
+        "BRANGELINA"
+    );
+    assert_eq!(
+        generator
+            .candidates("brad", "angelina")
+            .next()
+            .unwrap()
+            .casing(),
+        Casing::Title
+    );
+}
+
+#[test]
+fn name_phrases() {
+    let generator = Portmanteau::builder().for_names().build();
+    assert_eq!(
+        generator
+            .generate("  Chicago Bulls ", "Boston Celtics")
+            .unwrap(),
+        "Chicagoston"
+    );
+    assert_eq!(generator.generate("", "Boston"), None);
+    assert_eq!(
+        generator.generate_many(&[
+            "Ben Affleck",
+            "Jennifer Lopez",
+            "Matt Damon"
+        ]),
+        generator.generate_many(&["ben", "jennifer", "matt"])
+    );
+
+    let left_word = generator.prepare("Brad Pitt");
+    let right_word = generator.prepare("Angelina Jolie");
+    assert_eq!(left_word.word(), "Brad");
+    assert_eq!(
+        generator.blend_prepared(&left_word, &right_word),
+        generator.blend("Brad Pitt", "Angelina Jolie")
+    );
+}
+
+#[test]
+fn either_order_swapped() {
+    assert_eq!(portmanteau("itches", "crutch").unwrap(), "itchutch");
+    let (crutches, order) = blend_either_order("itches", "crutch").unwrap();
+    assert_eq!(order, WordOrder::Swapped);
+    assert_eq!(Some(crutches), blend("crutch", "itches"));
+    assert_eq!(
+        Portmanteau::builder()
+            .either_order(true)
+            .build()
+            .generate("itches", "crutch")
+            .unwrap(),
+        "crutches"
+    );
+}
+
+#[test]
+fn either_order_better_score_wins() {
+    let generator = Portmanteau::default();
+    for (first_word, second_word) in [
+        ("liquid", "slinky"),
+        ("slinky", "liquid"),
+        ("chrome", "promise"),
+        ("promise", "chrome"),
+    ] {
+        let given = generator.blend(first_word, second_word).unwrap();
+        let swapped = generator.blend(second_word, first_word).unwrap();
+        let (blend, order) = generator
+            .blend_either_order(first_word, second_word)
+            .unwrap();
+        if generator.score(&swapped) > generator.score(&given) {
+            assert_eq!((blend, order), (swapped, WordOrder::Swapped));
+        } else {
+            assert_eq!((blend, order), (given, WordOrder::Given));
+        }
+    }
+}
+
+#[test]
+fn either_order_neither() {
+    assert_eq!(blend_either_order("tin", "can"), None);
+    assert_eq!(
+        Portmanteau::builder()
+            .either_order(true)
+            .build()
+            .try_blend("swords", "words"),
+        Err(PortmanteauError::AllRejected)
+    );
+}
+
+#[test]
+fn either_order_builder_option() {
+    let generator = Portmanteau::builder().either_order(true).build();
+    assert!(generator.tries_either_order());
+    assert!(!Portmanteau::default().tries_either_order());
+    assert_eq!(generator.try_blend("itches", "crutch").unwrap(), "crutches");
+    assert_eq!(
+        generator.best_blend("itches", "crutch"),
+        best_blend("crutch", "itches")
+    );
+    // Candidates are still in the order given
+    assert_eq!(
+        generator.candidates("itches", "crutch").next().unwrap(),
+        "itchutch"
+    );
+
+    let left_word = generator.prepare("itches");
+    let right_word = generator.prepare("crutch");
+    assert_eq!(
+        generator.blend_prepared(&left_word, &right_word).unwrap(),
+        "crutches"
+    );
+}
+
+#[test]
+fn min_kept_letters() {
+    let generator = Portmanteau::builder().min_kept_letters(4).build();
+    assert_eq!(generator.min_kept_letters(), 4);
+    assert_eq!(generator.generate("aisle", "closet").unwrap(), "aisloset");
+    // Shared letters count towards both words
+    assert_eq!(portmanteau("motor", "hotel").unwrap(), "mototel");
+    assert_eq!(generator.generate("motor", "hotel").unwrap(), "mototel");
+    assert_eq!(
+        Portmanteau::builder()
+            .min_kept_letters(5)
+            .build()
+            .generate("motor", "hotel"),
+        None
+    );
+
+    assert_eq!(portmanteau("crotch", "goblin").unwrap(), "croblin");
+    assert_eq!(
+        generator.try_blend("crotch", "goblin"),
+        Err(PortmanteauError::OutsideLimits)
+    );
+}
+
+#[test]
+fn min_kept_fraction() {
+    let half = Portmanteau::builder().min_kept_fraction(0.5).build();
+    assert_eq!(half.generate("crotch", "goblin").unwrap(), "croblin");
+    let most = Portmanteau::builder().min_kept_fraction(0.6).build();
+    assert_eq!(most.generate("crotch", "goblin"), None);
+    // Only keeps "l" of "hotel"
+    assert_eq!(portmanteau("acceptable", "hotel").unwrap(), "acceptablel");
+    assert_eq!(
+        half.generate("acceptable", "hotel").unwrap(),
+        "acceptablotel"
+    );
+}
+
+#[test]
+#[should_panic]
+fn min_kept_fraction_over_one() {
+    Portmanteau::builder().min_kept_fraction(1.5).build();
+}
+
+#[test]
+fn output_length() {
+    use std::ops::Bound;
+
+    let long = Portmanteau::builder().output_length(10..).build();
+    assert_eq!(
+        long.output_length(),
+        (Bound::Included(10), Bound::Unbounded)
+    );
+    assert_eq!(
+        long.generate("acceptable", "captain").unwrap(),
+        "acceptaptain"
+    );
+    let short = Portmanteau::builder().output_length(..=8).build();
+    assert_eq!(
+        short.try_blend("acceptable", "captain"),
+        Err(PortmanteauError::OutsideLimits)
+    );
+    let exact = Portmanteau::builder().output_length(9..10).build();
+    assert_eq!(
+        exact.generate("acceptable", "captain").unwrap(),
+        "acceptain"
+    );
+}
+
+#[test]
+fn reject_substrings() {
+    assert!(Portmanteau::default().rejects_substrings());
+    assert_eq!(portmanteau("basket", "football").unwrap(), "baskootball");
+    let generator = Portmanteau::builder().reject_substrings(false).build();
+    assert!(!generator.rejects_substrings());
+    assert_eq!(generator.generate("basket", "football").unwrap(), "ball");
+    assert_eq!(generator.generate("hotel", "motel").unwrap(), "hotel");
+    assert_eq!(
+        Portmanteau::builder()
+            .reject_substrings(false)
+            .clear_strategies()
+            .strategy(SharedTrios)
+            .build()
+            .try_blend("liquid", "slinky"),
+        Err(PortmanteauError::NoJoinPoints)
+    );
+}
+
+#[test]
+fn embed_words() {
+    let generator = Portmanteau::builder().embed_words(true).build();
+    assert!(generator.embeds_words());
+    let candidates = generator
+        .candidates("sports", "broadcast")
+        .collect::<Vec<_>>();
+    assert_eq!(candidates, ["spoadcast", "sportscast"]);
+    assert_eq!(candidates[1].left_fragment(), "sports");
+    assert_eq!(candidates[1].method(), JoinMethod::WholeWord);
+    // Keeping a word whole is scored higher
+    assert_eq!(
+        generator.best_blend("sports", "broadcast"),
+        Some(candidates[1])
+    );
+    assert_eq!(best_blend("sports", "broadcast").unwrap(), "spoadcast");
+
+    // The right word kept whole
+    let blend = generator.candidates("itches", "crutch").last().unwrap();
+    assert_eq!(blend, "itcrutch");
+    assert_eq!(blend.right_fragment(), "crutch");
+    assert_eq!(generator.generate("hotel", "motel").unwrap(), "hoteltel");
+}
+
+#[test]
+fn motel() {
+    // Neither word is kept whole, but short words share fewer letters
+    let generator = Portmanteau::builder()
+        .overlap(Overlap::Adaptive)
+        .embed_words(true)
+        .build();
+    assert_eq!(generator.blend("motor", "hotel").unwrap(), "motel");
+    assert_eq!(generator.best_blend("motor", "hotel").unwrap(), "motel");
+}
+
+#[test]
+fn compound_fallback() {
+    let generator = Portmanteau::builder().compound_fallback(true).build();
+    assert!(generator.falls_back_to_compounds());
+    // Only tried if nothing else works
+    assert_eq!(generator.generate("motor", "hotel").unwrap(), "mototel");
+
+    let blend = generator.blend("hotel", "motel").unwrap();
+    assert_eq!(blend, "hotelmotel");
+    assert_eq!(blend.method(), JoinMethod::Compound);
+    // Shared letters are only written once
+    let blend = generator.try_blend("sport", "transport").unwrap();
+    assert_eq!(blend, "sportransport");
+    assert_eq!(blend.right_fragment(), "ransport");
+}
+
+#[test]
+fn acceptance_prepared() {
+    let generators = [
+        Portmanteau::builder().reject_substrings(false).build(),
+        Portmanteau::builder().embed_words(true).build(),
+        Portmanteau::builder().compound_fallback(true).build(),
+    ];
+    let words = ["basket", "football", "Sports", "broadcast", "hotel"];
+    for generator in &generators {
+        let prepared = words
+            .iter()
+            .map(|word| generator.prepare(word))
+            .collect::<Vec<_>>();
+        for left_word in &prepared {
+            for right_word in &prepared {
+                assert_eq!(
+                    generator.blend_prepared(left_word, right_word),
+                    generator.blend(left_word.word(), right_word.word())
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn write_to() {
+    let mut buffer = String::new();
+    for (left_word, right_word) in [("liquid", "slinky"), ("CHROME", "PROMISE")]
+    {
+        let blend = blend(left_word, right_word).unwrap();
+        blend.write_to(&mut buffer).unwrap();
+        buffer.push(' ');
+    }
+    assert_eq!(buffer, "liquinky CHROMISE ");
+    write!(buffer, "{}", blend("Liquid", "slinky").unwrap()).unwrap();
+    assert_eq!(buffer, "liquinky CHROMISE Liquinky");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn write_io() {
+    let pairs = [
+        ("liquid", "slinky"),
+        ("Liquid", "Slinky"),
+        ("Straße", "Liquid"),
+        ("STRAßE", "LIQUID"),
+    ];
+    for (left_word, right_word) in pairs {
+        let blend = blend(left_word, right_word).unwrap();
+        let mut bytes = Vec::new();
+        blend.write_io(&mut bytes).unwrap();
+        assert_eq!(String::from_utf8(bytes).unwrap(), blend.to_string());
+    }
+
+    // Longer than a batch of capitalised letters
+    let left_word = "A".repeat(40) + "LIQUID";
+    let right_word = "SLINKY".to_string() + &"É".repeat(40);
+    let blend = blend(&left_word, &right_word).unwrap();
+    assert_eq!(blend.casing(), Casing::Upper);
+    let mut bytes = Vec::new();
+    blend.write_io(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), blend.len());
+    assert_eq!(String::from_utf8(bytes).unwrap(), blend.to_string());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn write_io_full() {
+    let mut buffer = [0; 4];
+    let blend = blend("liquid", "slinky").unwrap();
+    assert!(blend.write_io(&mut &mut buffer[..]).is_err());
+    assert_eq!(&buffer, b"liqu");
+}